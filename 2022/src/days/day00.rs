@@ -0,0 +1,21 @@
+use crate::{Answer, Solution};
+
+pub struct Day00;
+
+impl Solution for Day00 {
+    const DAY: u8 = 0;
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+    }
+
+    fn part_one(_input: &Self::Input<'_>) -> Answer {
+        0.into()
+    }
+
+    fn part_two(_input: &Self::Input<'_>) -> Answer {
+        1.into()
+    }
+}
@@ -1,14 +1,28 @@
 // See: https://adventofcode.com/2022/day/1
-use aoc::read_file_input;
+use crate::{Answer, Solution};
 
-fn main() {
-    let res = solve(read_file_input("01.txt".to_string()));
+pub struct Day01;
 
-    println!("{}", res.0);
-    println!("{}", res.1);
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input<'a> = (i32, i32, i32);
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        solve(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        input.0.into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        (input.0 + input.1 + input.2).into()
+    }
 }
 
-fn solve(input: String) -> (i32, i32) {
+// Find the three largest calorie totals carried by any elf.
+fn solve(input: &str) -> (i32, i32, i32) {
     let mut current = 0;
     let mut max: (i32, i32, i32) = (0, 0, 0);
 
@@ -21,9 +35,7 @@ fn solve(input: String) -> (i32, i32) {
         }
     }
 
-    max = calculate(current, max);
-
-    (max.0, max.0 + max.1 + max.2)
+    calculate(current, max)
 }
 
 fn calculate(current: i32, max: (i32, i32, i32)) -> (i32, i32, i32) {
@@ -38,23 +50,5 @@ fn calculate(current: i32, max: (i32, i32, i32)) -> (i32, i32, i32) {
     } else if current > max.2 {
         max.2 = current;
     }
-    return max;
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use aoc::read_test_file_input;
-
-    #[test]
-    fn test_solve_one() {
-        let res = solve(read_test_file_input("01_one.txt".to_string()));
-        assert_eq!(res.0, 24000);
-    }
-
-    #[test]
-    fn test_solve_two() {
-        let res = solve(read_test_file_input("01_one.txt".to_string()));
-        assert_eq!(res.1, 45000);
-    }
+    max
 }
@@ -1,13 +1,27 @@
-use aoc::read_file_input;
+use crate::{Answer, Solution};
 
-fn main() {
-    let res = solve(read_file_input("02.txt".to_string()));
+pub struct Day02;
 
-    println!("{}", res.0);
-    println!("{}", res.1);
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input<'a> = (u32, u32);
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        solve(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        input.0.into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        input.1.into()
+    }
 }
 
-fn solve(input: String) -> (u32, u32) {
+// Both strategies are scored in a single pass over the rounds.
+fn solve(input: &str) -> (u32, u32) {
     let mut score1 = 0;
     let mut score2 = 0;
 
@@ -66,21 +80,3 @@ fn solve(input: String) -> (u32, u32) {
 
     (score1, score2)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use aoc::read_test_file_input;
-
-    #[test]
-    fn test_solve_one() {
-        let res = solve(read_test_file_input("02_one.txt".to_string()));
-        assert_eq!(res.0, 15);
-    }
-
-    #[test]
-    fn test_solve_two() {
-        let res = solve(read_test_file_input("02_one.txt".to_string()));
-        assert_eq!(res.1, 12);
-    }
-}
@@ -0,0 +1,73 @@
+use crate::{Answer, Solution};
+
+const ALPHABET: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        solve_part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        solve_part_two(input).into()
+    }
+}
+
+fn solve_part_one(input: &str) -> usize {
+    let mut sum: usize = 0;
+
+    for bag in input.lines() {
+        let compartments = bag.split_at(bag.len() / 2);
+
+        for char in compartments.0.chars() {
+            if compartments.1.contains(char) {
+                if let Some(index) = ALPHABET.find(char) {
+                    sum += index + 1;
+                    break;
+                }
+            }
+        }
+    }
+
+    sum
+}
+
+fn solve_part_two(input: &str) -> usize {
+    let mut sum: usize = 0;
+    let mut lines = input.lines();
+
+    while let (Some(elf1_bag), Some(elf2_bag), Some(elf3_bag)) =
+        (lines.next(), lines.next(), lines.next())
+    {
+        for char in elf1_bag.chars() {
+            if elf2_bag.contains(char) && elf3_bag.contains(char) {
+                if let Some(index) = ALPHABET.find(char) {
+                    sum += index + 1;
+                    break;
+                }
+            }
+        }
+    }
+
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_known_answers;
+
+    #[test]
+    fn known_answers() {
+        assert_known_answers::<Day03>();
+    }
+}
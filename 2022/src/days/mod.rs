@@ -0,0 +1,13 @@
+use aoc_core::Day;
+
+pub mod day00;
+pub mod day01;
+pub mod day02;
+pub mod day03;
+
+/// Every solved day of the year, in order.
+pub const DAYS: &[Day] = &[
+    Day::new::<day01::Day01>(),
+    Day::new::<day02::Day02>(),
+    Day::new::<day03::Day03>(),
+];
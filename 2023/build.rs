@@ -0,0 +1,7 @@
+use std::{env, path::Path};
+
+// Generates a test per example listed in `src/test_inputs/examples.toml`.
+fn main() {
+    let out = Path::new(&env::var_os("OUT_DIR").unwrap()).join("examples.rs");
+    aoc_core::examples::write_tests(Path::new("src/test_inputs"), &out).unwrap();
+}
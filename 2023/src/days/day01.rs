@@ -1,6 +1,7 @@
-use aoc::read_file_input;
 use regex::Regex;
 
+use crate::{Answer, Solution};
+
 #[derive(Debug)]
 struct Pair {
     first: u32,
@@ -17,23 +18,32 @@ impl PairTrait for Pair {
     }
 }
 
-fn main() {
-    let part_one_total = solve_part_one(read_file_input("01.txt".to_string()));
-    println!("Part One Total: {}", part_one_total);
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        solve_part_one(input).into()
+    }
 
-    let part_two_total = solve_part_two(read_file_input("01.txt".to_string()));
-    println!("Part Two Total: {}", part_two_total);
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        solve_part_two(input).into()
+    }
 }
 
-fn solve_part_one(input: String) -> u32 {
+fn solve_part_one(input: &str) -> u32 {
     input
         .lines()
         .map(|line| {
             line.chars()
-                .map(|c| match c.to_digit(10) {
-                    Some(n) => n,
-                    None => 0,
-                })
+                .map(|c| c.to_digit(10).unwrap_or_default())
                 .collect::<Vec<u32>>()
         })
         .map(|v| {
@@ -55,13 +65,14 @@ fn solve_part_one(input: String) -> u32 {
         .sum::<u32>()
 }
 
-fn solve_part_two(input: String) -> u32 {
+fn solve_part_two(input: &str) -> u32 {
+    let re = Regex::new(r"(oneight|twone|threeight|fiveight|sevenine|eightwo|eighthree|nineight|one|two|three|four|five|six|seven|eight|nine|\d)")
+        .unwrap();
+
     input
         .lines()
         .map(|line| {
-            Regex::new(r"(oneight|twone|threeight|fiveight|sevenine|eightwo|eighthree|nineight|one|two|three|four|five|six|seven|eight|nine|\d)")
-                .unwrap()
-                .find_iter(line)
+            re.find_iter(line)
                 .flat_map(|m| match m.as_str() {
                     "one" => vec![1],
                     "two" => vec![2],
@@ -109,25 +120,3 @@ fn solve_part_two(input: String) -> u32 {
         .map(|f| f.result())
         .sum::<u32>()
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use aoc::read_test_file_input;
-
-    #[test]
-    fn test_solve_one() {
-        assert_eq!(
-            solve_part_one(read_test_file_input("01_one.txt".to_string())),
-            142
-        );
-    }
-
-    #[test]
-    fn test_solve_two() {
-        assert_eq!(
-            solve_part_two(read_test_file_input("01_two.txt".to_string())),
-            281
-        );
-    }
-}
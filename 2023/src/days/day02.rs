@@ -1,20 +1,52 @@
-use aoc::read_file_input;
 use std::collections::HashMap;
 
-fn main() {
-    let res = solve(read_file_input("02.txt".to_string()));
+use crate::{Answer, Solution};
 
-    println!("{}", res.0);
-    println!("{}", res.1);
+pub struct Game<'a> {
+    id: u32,
+    min_counts: HashMap<&'a str, u32>,
+    possible: bool,
 }
 
-fn solve(input: String) -> (u32, u32) {
-    let mut p1 = 0;
-    let mut p2 = 0;
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input<'a> = Vec<Game<'a>>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse(input)
+    }
+
+    fn part_one(games: &Self::Input<'_>) -> Answer {
+        games
+            .iter()
+            .filter(|game| game.possible)
+            .map(|game| game.id)
+            .sum::<u32>()
+            .into()
+    }
+
+    fn part_two(games: &Self::Input<'_>) -> Answer {
+        games
+            .iter()
+            .map(|game| {
+                game.min_counts.get("red").unwrap_or(&0)
+                    * game.min_counts.get("green").unwrap_or(&0)
+                    * game.min_counts.get("blue").unwrap_or(&0)
+            })
+            .sum::<u32>()
+            .into()
+    }
+}
+
+fn parse(input: &str) -> Vec<Game<'_>> {
+    let mut games = Vec::new();
 
     for line in input.lines() {
         let game_id: u32 = line
-            .split(":")
+            .split(':')
             .next()
             .unwrap()
             .split_whitespace()
@@ -26,7 +58,7 @@ fn solve(input: String) -> (u32, u32) {
         let mut min_counts: HashMap<&str, u32> = HashMap::new();
         let mut possible = true;
 
-        for part in line.split(":").nth(1).unwrap().split(";") {
+        for part in line.split(':').nth(1).unwrap().split(';') {
             let mut counts: HashMap<&str, u32> = HashMap::new();
 
             for segment in part.split(", ") {
@@ -49,32 +81,12 @@ fn solve(input: String) -> (u32, u32) {
             }
         }
 
-        if possible {
-            p1 += game_id;
-        }
-
-        p2 += min_counts.get("red").unwrap_or(&0)
-            * min_counts.get("green").unwrap_or(&0)
-            * min_counts.get("blue").unwrap_or(&0);
+        games.push(Game {
+            id: game_id,
+            min_counts,
+            possible,
+        });
     }
 
-    (p1, p2)
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use aoc::read_test_file_input;
-
-    #[test]
-    fn test_solve_one() {
-        let res = solve(read_test_file_input("02_one.txt".to_string()));
-        assert_eq!(res.0, 8);
-    }
-
-    #[test]
-    fn test_solve_two() {
-        let res = solve(read_test_file_input("02_two.txt".to_string()));
-        assert_eq!(res.1, 2286);
-    }
+    games
 }
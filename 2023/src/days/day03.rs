@@ -1,18 +1,34 @@
-use aoc::read_file_input;
-use std::{
-    collections::{HashMap, HashSet},
-    u32,
-};
+use std::collections::{HashMap, HashSet};
 
-fn main() {
-    let res = solve(read_file_input("03.txt".to_string()));
+use crate::{Answer, Solution};
 
-    // Print the results
-    println!("{}", res.0);
-    println!("{}", res.1);
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input<'a> = Vec<Vec<u32>>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse(input)
+    }
+
+    fn part_one(parts: &Self::Input<'_>) -> Answer {
+        parts.iter().flatten().sum::<u32>().into()
+    }
+
+    fn part_two(parts: &Self::Input<'_>) -> Answer {
+        parts
+            .iter()
+            .filter(|p| p.len() == 2)
+            .map(|p| p.iter().product::<u32>())
+            .sum::<u32>()
+            .into()
+    }
 }
 
-fn solve(input: String) -> (u32, u32) {
+// Collect the part numbers adjacent to each symbol on the board.
+fn parse(input: &str) -> Vec<Vec<u32>> {
     let mut parts: HashMap<(usize, usize), Vec<u32>> = HashMap::new();
     let mut chars: HashSet<(usize, usize)> = HashSet::new();
     let mut board: Vec<Vec<char>> = Vec::new();
@@ -20,7 +36,7 @@ fn solve(input: String) -> (u32, u32) {
     for (r, line) in input.lines().enumerate() {
         let row: Vec<char> = line.chars().collect();
         for (c, ch) in row.iter().enumerate() {
-            if !ch.is_digit(10) && *ch != '.' {
+            if !ch.is_ascii_digit() && *ch != '.' {
                 chars.insert((r, c));
             }
         }
@@ -28,11 +44,9 @@ fn solve(input: String) -> (u32, u32) {
     }
 
     // Iterate through the board to calcaulate results
+    let re = regex::Regex::new(r"\d+").unwrap();
     for (r, row) in board.iter().enumerate() {
-        for m in regex::Regex::new(r"\d+")
-            .unwrap()
-            .find_iter(&row.iter().collect::<String>())
-        {
+        for m in re.find_iter(&row.iter().collect::<String>()) {
             //println!("m={}", m.as_str());
             //println!("start={} | end={}", m.start().to_string(), m.end().to_string());
 
@@ -53,7 +67,7 @@ fn solve(input: String) -> (u32, u32) {
             for &c in nexts.intersection(&chars) {
                 parts
                     .entry(c)
-                    .or_insert_with(Vec::new)
+                    .or_default()
                     .push(m.as_str().parse().unwrap());
             }
         }
@@ -61,31 +75,5 @@ fn solve(input: String) -> (u32, u32) {
 
     //println!("{:?}", parts.values());
 
-    // Calcaulate the results
-    let p1: u32 = parts.values().flatten().sum();
-    let p2: u32 = parts
-        .values()
-        .filter(|p| p.len() == 2)
-        .map(|p| p.iter().product::<u32>())
-        .sum();
-
-    (p1, p2)
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use aoc::read_test_file_input;
-
-    #[test]
-    fn test_solve_one() {
-        let res = solve(read_test_file_input("03_one.txt".to_string()));
-        assert_eq!(res.0, 4361);
-    }
-
-    #[test]
-    fn test_solve_two() {
-        let res = solve(read_test_file_input("03_two.txt".to_string()));
-        assert_eq!(res.1, 467835);
-    }
+    parts.into_values().collect()
 }
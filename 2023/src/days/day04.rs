@@ -0,0 +1,49 @@
+use std::collections::HashSet;
+
+use crate::{Answer, Solution};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input<'a> = Vec<i32>;
+
+    // Count the winning numbers on each card.
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+            .lines()
+            .map(|line| {
+                let s: Vec<HashSet<i32>> = line
+                    .split(':')
+                    .nth(1)
+                    .unwrap()
+                    .split('|')
+                    .map(|s| s.split_whitespace().map(|n| n.parse().unwrap()).collect())
+                    .collect();
+
+                s[0].intersection(&s[1]).count() as i32
+            })
+            .collect()
+    }
+
+    fn part_one(wins: &Self::Input<'_>) -> Answer {
+        wins.iter()
+            .filter(|&&s2| s2 > 0)
+            .map(|&s2| 2_i32.pow(s2 as u32 - 1))
+            .sum::<i32>()
+            .into()
+    }
+
+    fn part_two(wins: &Self::Input<'_>) -> Answer {
+        let mut matches: Vec<[i32; 2]> = wins.iter().map(|&s2| [1, s2]).collect();
+
+        for i in 0..matches.len() {
+            for j in 1..=matches[i][1] {
+                matches[i + j as usize][0] += matches[i][0];
+            }
+        }
+
+        matches.iter().map(|&m| m[0]).sum::<i32>().into()
+    }
+}
@@ -0,0 +1,89 @@
+use itertools::Itertools;
+
+use crate::{
+    interval::{Interval, IntervalSet, RangeMap},
+    Answer, Solution,
+};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        solve_part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        solve_part_two(input).into()
+    }
+}
+
+// Returns the seeds and the maps from one category to the next, in order.
+fn parse(input: &str) -> (Vec<i64>, Vec<RangeMap>) {
+    // Split up the subsections
+    let mut parts = input.split("\n\n");
+
+    // Collect the seeds
+    let seeds = parts
+        .next()
+        .unwrap()
+        .split_once(": ")
+        .unwrap()
+        .1
+        .split_whitespace()
+        .map(|s| s.parse().unwrap())
+        .collect();
+
+    // Each line of a map is `destination source length`.
+    let mappings = parts
+        .map(|mapping| {
+            mapping
+                .lines()
+                .skip(1)
+                .map(|line| {
+                    let (destination, source, len) = line
+                        .split(' ')
+                        .map(|n| n.parse::<i64>().unwrap())
+                        .collect_tuple()
+                        .unwrap();
+                    (Interval::with_len(source, len), destination - source)
+                })
+                .collect()
+        })
+        .collect();
+
+    (seeds, mappings)
+}
+
+fn solve_part_one(input: &str) -> i64 {
+    let (seeds, mappings) = parse(input);
+
+    // Location mapping.
+    seeds
+        .into_iter()
+        .map(|seed| mappings.iter().fold(seed, |id, mapping| mapping.map(id)))
+        .min()
+        .unwrap()
+}
+
+fn solve_part_two(input: &str) -> i64 {
+    let (seeds, mappings) = parse(input);
+
+    let seed_ranges: IntervalSet = seeds
+        .chunks(2)
+        .map(|s| Interval::with_len(s[0], s[1]))
+        .collect();
+
+    mappings
+        .iter()
+        .fold(seed_ranges, |ranges, mapping| mapping.map_set(&ranges))
+        .min()
+        .unwrap()
+}
@@ -0,0 +1,82 @@
+use regex::Regex;
+
+use crate::{math, Answer, Solution};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input<'a> = (&'a str, &'a str);
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.trim().split_once('\n').unwrap()
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        let (times, distances) = input;
+        calculate(times, distances).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        let (times, distances) = input;
+        calculate(&times.replace(' ', ""), &distances.replace(' ', "")).into()
+    }
+}
+
+fn calculate(times: &str, distances: &str) -> i64 {
+    let re = Regex::new(r"\d+").unwrap();
+
+    let t: Vec<u64> = re
+        .find_iter(times)
+        .map(|m| m.as_str().parse().unwrap())
+        .collect();
+    let d: Vec<u64> = re
+        .find_iter(distances)
+        .map(|m| m.as_str().parse().unwrap())
+        .collect();
+
+    t.iter()
+        .zip(d.iter())
+        .fold(1, |acc, (&time, &best_distance)| {
+            acc * ways_to_win(time, best_distance) as i64
+        })
+}
+
+// Holding the button for `i` of `time` milliseconds travels `i * (time - i)`,
+// which beats `distance` between the roots of `i² - time * i + distance`. The
+// integer square root gives the lower root up to rounding, which the loop
+// fixes, and the winning holds are symmetric around `time / 2`.
+fn ways_to_win(time: u64, distance: u64) -> u64 {
+    let (time, distance) = (time as u128, distance as u128);
+    let Some(discriminant) = (time * time).checked_sub(4 * distance) else {
+        return 0;
+    };
+
+    let mut low = (time - math::isqrt_u128(discriminant)) / 2;
+    while low <= time / 2 && low * (time - low) <= distance {
+        low += 1;
+    }
+    if low > time / 2 {
+        return 0;
+    }
+
+    (time - 2 * low + 1) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ways_to_win() {
+        assert_eq!(ways_to_win(7, 9), 4);
+        assert_eq!(ways_to_win(30, 200), 9);
+        assert_eq!(ways_to_win(4, 4), 0);
+        assert_eq!(ways_to_win(3, 100), 0);
+        for (time, distance) in [(71530, 940200), (50, 600), (51, 649)] {
+            let brute = (0..=time).filter(|&i| i * (time - i) > distance).count();
+            assert_eq!(ways_to_win(time, distance), brute as u64);
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use aoc::read_file_input;
+use crate::{Answer, Solution};
 
 struct Hand {
     bid: u32,
@@ -9,8 +9,8 @@ impl Hand {
     fn new(line: &str, with_jokers: bool) -> Self {
         let l: Vec<_> = line.split_whitespace().collect();
 
-        let mut card_ch = l.iter().nth(0).unwrap().chars();
-        let bid: u32 = l.iter().nth(1).unwrap().parse().unwrap();
+        let mut card_ch = l[0].chars();
+        let bid: u32 = l[1].parse().unwrap();
 
         let mut strength: u32 = 0;
         let mut jokers = 0;
@@ -36,7 +36,7 @@ impl Hand {
                             8
                         }
                     }
-                    n => n.to_digit(10).unwrap() as u32 - (if with_jokers { 1 } else { 2 }),
+                    n => n.to_digit(10).unwrap() - (if with_jokers { 1 } else { 2 }),
                 };
 
                 if with_jokers && val == 0 {
@@ -66,18 +66,24 @@ impl Hand {
     }
 }
 
-fn main() {
-    let res = solve(read_file_input("07.txt".to_string()));
+pub struct Day07;
 
-    println!("{}", res.0);
-    println!("{}", res.1);
-}
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+    }
 
-fn solve(input: String) -> (usize, usize) {
-    let p1 = calculate_winnings(&input, false);
-    let p2 = calculate_winnings(&input, true);
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        calculate_winnings(input, false).into()
+    }
 
-    (p1, p2)
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        calculate_winnings(input, true).into()
+    }
 }
 
 fn calculate_winnings(inputs: &str, with_jokers: bool) -> usize {
@@ -91,35 +97,15 @@ fn calculate_winnings(inputs: &str, with_jokers: bool) -> usize {
         .iter()
         .enumerate()
         .fold(0, |acc, (i, hand)| acc + ((i + 1) * hand.bid as usize))
-        .into()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::{read_file_input, read_test_file_input};
-
-    #[test]
-    fn test_solve_one() {
-        let res = solve(read_test_file_input("07_one.txt".to_string()));
-        assert_eq!(res.0, 6440);
-    }
-
-    #[test]
-    fn test_solve_two() {
-        let res = solve(read_test_file_input("07_one.txt".to_string()));
-        assert_eq!(res.1, 5905);
-    }
-
-    #[test]
-    fn actual_solve_one() {
-        let res = solve(read_file_input("07.txt".to_string()));
-        assert_eq!(res.0, 252656917);
-    }
+    use crate::assert_known_answers;
 
     #[test]
-    fn actual_solve_two() {
-        let res = solve(read_file_input("07.txt".to_string()));
-        assert_eq!(res.1, 253499763);
+    fn known_answers() {
+        assert_known_answers::<Day07>();
     }
 }
@@ -1,8 +1,9 @@
 use std::collections::HashMap;
 
-use aoc::read_file_input;
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 
+use crate::{math::lcm, Answer, Solution};
+
 #[derive(Debug, Default)]
 struct Node<'a> {
     id: &'a str,
@@ -12,18 +13,24 @@ struct Node<'a> {
     right_idx: usize,
 }
 
-fn main() {
-    let res = solve(read_file_input("08.txt".to_string()));
+pub struct Day08;
 
-    println!("{}", res.0);
-    println!("{}", res.1);
-}
+impl Solution for Day08 {
+    const DAY: u8 = 8;
 
-fn solve(input: String) -> (usize, usize) {
-    let p1 = solve_part_one(&input);
-    let p2 = solve_part_two(&input);
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+    }
 
-    (p1, p2)
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        solve_part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        solve_part_two(input).into()
+    }
 }
 
 fn solve_part_one<'a>(input: &'a str) -> usize {
@@ -74,7 +81,7 @@ fn solve_part_one<'a>(input: &'a str) -> usize {
     iterations
 }
 
-fn solve_part_two<'a>(input: &'a str) -> usize {
+fn solve_part_two<'a>(input: &'a str) -> u64 {
     let (instructions_str, nodes_str) = input.split_once("\n\n").unwrap();
     let mut nodes: Vec<Node> = Vec::new();
     let mut starting_node_indexes: Vec<usize> = Vec::new();
@@ -135,58 +142,17 @@ fn solve_part_two<'a>(input: &'a str) -> usize {
                 }
             }
         })
-        .reduce_with(|a, b| lcm(a, b))
+        .reduce_with(lcm)
         .unwrap()
 }
 
-// greatest common divisor
-fn gcd(mut a: usize, mut b: usize) -> usize {
-    while b > 0 {
-        let remainder = a % b;
-        a = b;
-        b = remainder;
-    }
-
-    a
-}
-
-// least common multiple
-fn lcm(a: usize, b: usize) -> usize {
-    a * b / gcd(a, b)
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::{read_file_input, read_test_file_input};
-
-    #[test]
-    fn test_solve_one_a() {
-        let res = solve_part_one(&read_test_file_input("08_one_a.txt".to_string()));
-        assert_eq!(res, 2);
-    }
-
-    #[test]
-    fn test_solve_one_b() {
-        let res = solve_part_one(&read_test_file_input("08_one_b.txt".to_string()));
-        assert_eq!(res, 6);
-    }
-
-    #[test]
-    fn test_solve_two() {
-        let res = solve_part_two(&read_test_file_input("08_two.txt".to_string()));
-        assert_eq!(res, 6);
-    }
-
-    #[test]
-    fn actual_solve_one() {
-        let res = solve_part_one(&read_file_input("08.txt".to_string()));
-        assert_eq!(res, 20093);
-    }
+    use crate::assert_known_answers;
 
     #[test]
-    fn actual_solve_two() {
-        let res = solve_part_two(&read_file_input("08.txt".to_string()));
-        assert_eq!(res, 22103062509257);
+    fn known_answers() {
+        assert_known_answers::<Day08>();
     }
 }
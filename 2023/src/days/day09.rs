@@ -1,14 +1,28 @@
-use aoc::read_file_input;
 use itertools::Itertools;
 
-fn main() {
-    let res = solve(read_file_input("09.txt".to_string()));
+use crate::{Answer, Solution};
 
-    println!("{}", res.0);
-    println!("{}", res.1);
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input<'a> = Vec<Vec<Vec<i64>>>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        solve_part(input, false).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        solve_part(input, true).into()
+    }
 }
 
-fn calulate_differences(history: &Vec<Vec<i64>>) -> Vec<Vec<Vec<i64>>> {
+fn calulate_differences(history: &[Vec<i64>]) -> Vec<Vec<Vec<i64>>> {
     history
         .iter()
         .map(|h| {
@@ -29,7 +43,7 @@ fn calulate_differences(history: &Vec<Vec<i64>>) -> Vec<Vec<Vec<i64>>> {
         .collect()
 }
 
-fn solve_part(diffs: &Vec<Vec<Vec<i64>>>, reverse: bool) -> i64 {
+fn solve_part(diffs: &[Vec<Vec<i64>>], reverse: bool) -> i64 {
     diffs
         .iter()
         .map(|diff| {
@@ -44,7 +58,7 @@ fn solve_part(diffs: &Vec<Vec<Vec<i64>>>, reverse: bool) -> i64 {
         .sum()
 }
 
-fn solve(input: String) -> (i64, i64) {
+fn parse(input: &str) -> Vec<Vec<Vec<i64>>> {
     let history: Vec<Vec<i64>> = input
         .lines()
         .map(|line| {
@@ -55,30 +69,5 @@ fn solve(input: String) -> (i64, i64) {
         .collect();
     //println!("{:?}", history);
 
-    let diffs = calulate_differences(&history);
-    //println!("{:?}", diffs);
-
-    let p1: i64 = solve_part(&diffs, false);
-    let p2: i64 = solve_part(&diffs, true);
-    //println!("p1={} | p2={}", p1, p2);
-
-    (p1, p2)
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use aoc::read_test_file_input;
-
-    #[test]
-    fn test_solve_one() {
-        let res = solve(read_test_file_input("09_one.txt".to_string()));
-        assert_eq!(res.0, 114);
-    }
-
-    #[test]
-    fn test_solve_two() {
-        let res = solve(read_test_file_input("09_one.txt".to_string()));
-        assert_eq!(res.1, 2);
-    }
+    calulate_differences(&history)
 }
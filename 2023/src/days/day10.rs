@@ -0,0 +1,89 @@
+use std::iter::{from_fn, once};
+
+use crate::{Answer, Grid, Solution};
+
+macro_rules! check {
+    {$grid:expr, $pos:expr, $delta:expr, $bit:expr, $($checks:expr),*} => {
+        match $grid.offset($pos, $delta).map(|pos| $grid[pos]) {
+            Some($($checks)|*) => 1 << $bit,
+            _ => 0,
+        }
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        solve_part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        solve_part_two(input).into()
+    }
+}
+
+fn parse(input: &str) -> ((usize, usize), Grid<u8>) {
+    let mut grid = Grid::parse(input, |tile| tile).unwrap();
+    let start = grid.find(&b'S').expect("Cannot parse input");
+
+    let bend = check!(grid, start, (0, -1), 3, b'|', b'F', b'7') // up
+        | check!(grid, start, (0, 1), 2, b'|', b'L', b'J') // down
+        | check!(grid, start, (1, 0), 1, b'-', b'7', b'J') // right
+        | check!(grid, start, (-1, 0), 0, b'-', b'L', b'F'); // left
+    grid[start] = b"XXX-X7FXXJLX|XXX"[bend];
+
+    (start, grid)
+}
+
+fn walk(start: (usize, usize), grid: &Grid<u8>) -> impl Iterator<Item = (usize, usize)> + '_ {
+    let mut position = start;
+    let mut direction = match grid[start] {
+        b'|' | b'F' | b'7' => (0, 1), // down
+        b'J' | b'L' => (0, -1),       // up
+        _ => (1, 0),                  // right
+    };
+    once(start).chain(from_fn(move || {
+        position = grid.offset(position, direction).unwrap();
+        (position != start).then(|| {
+            direction = match (direction, grid[position]) {
+                ((0, 1), b'L') | ((0, -1), b'F') => (1, 0),  // right
+                ((0, 1), b'J') | ((0, -1), b'7') => (-1, 0), // left
+                ((1, 0), b'7') | ((-1, 0), b'F') => (0, 1),  // down
+                ((1, 0), b'J') | ((-1, 0), b'L') => (0, -1), // up
+                _ => direction,
+            };
+            position
+        })
+    }))
+}
+
+fn solve_part_one(input: &str) -> usize {
+    let (start, grid) = parse(input);
+    walk(start, &grid).count() / 2
+}
+
+fn solve_part_two(input: &str) -> usize {
+    let (start, grid) = parse(input);
+    let mut blockers = Grid::new(grid.width(), grid.height(), b'.');
+    for position in walk(start, &grid) {
+        blockers[position] = grid[position];
+    }
+    let mut inside = false;
+    blockers
+        .iter()
+        .filter(|&((x, _), &i)| {
+            inside &= x != 0;
+            inside ^= matches!(i, b'|' | b'J' | b'L');
+            inside && i == b'.'
+        })
+        .count()
+}
@@ -1,21 +1,35 @@
-#![feature(cmp_minmax)]
 use std::cmp;
 
-use aoc::read_file_input;
 use itertools::Itertools;
 
-fn main() {
-    let res = solve(&read_file_input("11.txt".to_string()));
+use crate::{Answer, Params, Solution};
 
-    println!("{}", res.0);
-    println!("{}", res.1);
-}
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        Self::part_one_with(input, &Params::default())
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        Self::part_two_with(input, &Params::default())
+    }
 
-fn solve(input: &str) -> (usize, usize) {
-    let p1 = calculate(input, 2);
-    let p2 = calculate(input, 1_000_000);
+    fn part_one_with(input: &Self::Input<'_>, params: &Params) -> Answer {
+        calculate(input, params.get_or("expansion", 2)).into()
+    }
 
-    (p1, p2)
+    fn part_two_with(input: &Self::Input<'_>, params: &Params) -> Answer {
+        calculate(input, params.get_or("expansion", 1_000_000)).into()
+    }
 }
 
 fn calculate(input: &str, expansion_factor: usize) -> usize {
@@ -66,35 +80,10 @@ fn distance_between(stars: &[usize], expansion_factor: usize) -> usize {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::read_test_file_input;
-
-    #[test]
-    fn test_solve_one() {
-        let res = calculate(&read_test_file_input("11_one.txt".to_string()), 2);
-        assert_eq!(res, 374);
-    }
-
-    #[test]
-    fn test_solve_two_a() {
-        let res = calculate(&read_test_file_input("11_one.txt".to_string()), 10);
-        assert_eq!(res, 1030);
-    }
-
-    #[test]
-    fn test_solve_two_b() {
-        let res = calculate(&read_test_file_input("11_one.txt".to_string()), 100);
-        assert_eq!(res, 8410);
-    }
-
-    #[test]
-    fn actual_solve_one() {
-        let res = calculate(&read_file_input("11.txt".to_string()), 2);
-        assert_eq!(res, 9509330);
-    }
+    use crate::assert_known_answers;
 
     #[test]
-    fn actual_solve_two() {
-        let res = calculate(&read_file_input("11.txt".to_string()), 1_000_000);
-        assert_eq!(res, 635832237682);
+    fn known_answers() {
+        assert_known_answers::<Day11>();
     }
 }
@@ -1,19 +1,25 @@
 use std::collections::HashMap;
 
-use aoc::read_file_input;
+use crate::{Answer, Solution};
 
-fn main() {
-    let res = solve(read_file_input("12.txt".to_string()));
+pub struct Day12;
 
-    println!("{}", res.0);
-    println!("{}", res.1);
-}
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input<'a> = &'a str;
 
-fn solve(input: String) -> (usize, usize) {
-    let p1 = sum_arrangements(&input, false);
-    let p2 = sum_arrangements(&input, true);
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        sum_arrangements(input, false).into()
+    }
 
-    (p1, p2)
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        sum_arrangements(input, true).into()
+    }
 }
 
 type Cache = HashMap<(Vec<usize>, Vec<char>), usize>;
@@ -65,7 +71,7 @@ fn unfold_input(input: Vec<&str>) -> Vec<&str> {
         .collect::<Vec<&str>>()
 }
 
-fn calculate(chars: &Vec<char>, group_sizes: &Vec<usize>, cache: &mut Cache) -> usize {
+fn calculate(chars: &[char], group_sizes: &[usize], cache: &mut Cache) -> usize {
     if chars.is_empty() {
         if group_sizes.is_empty() {
             return 1;
@@ -74,18 +80,17 @@ fn calculate(chars: &Vec<char>, group_sizes: &Vec<usize>, cache: &mut Cache) ->
     }
 
     match chars[0] {
-        '.' => calculate(&chars[1..].to_vec(), group_sizes, cache),
+        '.' => calculate(&chars[1..], group_sizes, cache),
         '#' => calculate_hashed(group_sizes, chars, cache),
         '?' => {
-            calculate(&chars[1..].to_vec(), group_sizes, cache)
-                + calculate_hashed(group_sizes, chars, cache)
+            calculate(&chars[1..], group_sizes, cache) + calculate_hashed(group_sizes, chars, cache)
         }
         _ => panic!("Problem calculating"),
     }
 }
 
-fn calculate_hashed(group_sizes: &Vec<usize>, chars: &Vec<char>, cache: &mut Cache) -> usize {
-    if let Some(&res) = cache.get(&(group_sizes.clone(), chars.clone())) {
+fn calculate_hashed(group_sizes: &[usize], chars: &[char], cache: &mut Cache) -> usize {
+    if let Some(&res) = cache.get(&(group_sizes.to_vec(), chars.to_vec())) {
         return res;
     }
 
@@ -93,14 +98,12 @@ fn calculate_hashed(group_sizes: &Vec<usize>, chars: &Vec<char>, cache: &mut Cac
         return 0;
     }
 
-    let ps = group_sizes[0] as usize;
+    let ps = group_sizes[0];
     if chars.len() < ps {
         return 0;
     }
-    for i in 0..ps {
-        if chars[i] == '.' {
-            return 0;
-        }
+    if chars[..ps].contains(&'.') {
+        return 0;
     }
     if chars.len() == ps {
         if group_sizes.len() == 1 {
@@ -112,47 +115,18 @@ fn calculate_hashed(group_sizes: &Vec<usize>, chars: &Vec<char>, cache: &mut Cac
         return 0;
     }
 
-    let res = calculate(
-        &chars[(ps + 1)..].to_vec(),
-        &group_sizes[1..].to_vec(),
-        cache,
-    );
-    cache.insert((group_sizes.clone(), chars.clone()), res);
+    let res = calculate(&chars[(ps + 1)..], &group_sizes[1..], cache);
+    cache.insert((group_sizes.to_vec(), chars.to_vec()), res);
     res
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::read_test_file_input;
-
-    #[test]
-    fn test_solve_one_a() {
-        let res = sum_arrangements(&read_test_file_input("12_one_a.txt".to_string()), false);
-        assert_eq!(res, 6);
-    }
-
-    #[test]
-    fn test_solve_one_b() {
-        let res = sum_arrangements(&read_test_file_input("12_one_b.txt".to_string()), false);
-        assert_eq!(res, 21);
-    }
-
-    #[test]
-    fn test_solve_two() {
-        let res = sum_arrangements(&read_test_file_input("12_one_b.txt".to_string()), true);
-        assert_eq!(res, 525_152);
-    }
-
-    #[test]
-    fn actual_solve_one() {
-        let res = sum_arrangements(&read_file_input("12.txt".to_string()), false);
-        assert_eq!(res, 7_090);
-    }
+    use crate::assert_known_answers;
 
     #[test]
-    fn actual_solve_two() {
-        let res = sum_arrangements(&read_file_input("12.txt".to_string()), true);
-        assert_eq!(res, 6_792_010_726_878);
+    fn known_answers() {
+        assert_known_answers::<Day12>();
     }
 }
@@ -1,18 +1,25 @@
-use aoc::read_file_input;
 use itertools::{izip, Itertools};
 
-fn main() {
-    let res = solve(read_file_input("13.txt".to_string()));
+use crate::{Answer, Solution};
 
-    println!("{}", res.0);
-    println!("{}", res.1);
-}
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+    }
 
-fn solve(input: String) -> (usize, usize) {
-    let p1 = calculate(&input, false);
-    let p2 = calculate(&input, true);
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        calculate(input, false).into()
+    }
 
-    (p1, p2)
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        calculate(input, true).into()
+    }
 }
 
 fn calculate(input: &str, with_smudges: bool) -> usize {
@@ -44,7 +51,7 @@ fn calculate(input: &str, with_smudges: bool) -> usize {
         .sum::<usize>()
 }
 
-fn palindrome(input: &Vec<u32>, with_smudges: bool) -> usize {
+fn palindrome(input: &[u32], with_smudges: bool) -> usize {
     (1..input.len())
         .find(|&center| {
             let (left, right) = input.split_at(center);
@@ -69,29 +76,10 @@ fn palindrome(input: &Vec<u32>, with_smudges: bool) -> usize {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::read_test_file_input;
-
-    #[test]
-    fn test_solve_one() {
-        let res = solve(read_test_file_input("13_one.txt".to_string()));
-        assert_eq!(res.0, 405);
-    }
-
-    #[test]
-    fn test_solve_two() {
-        let res = solve(read_test_file_input("13_one.txt".to_string()));
-        assert_eq!(res.1, 400);
-    }
-
-    #[test]
-    fn actual_solve_one() {
-        let res = solve(read_file_input("13.txt".to_string()));
-        assert_eq!(res.0, 37718);
-    }
+    use crate::assert_known_answers;
 
     #[test]
-    fn actual_solve_two() {
-        let res = solve(read_file_input("13.txt".to_string()));
-        assert_eq!(res.1, 40995);
+    fn known_answers() {
+        assert_known_answers::<Day13>();
     }
 }
@@ -0,0 +1,131 @@
+use itertools::izip;
+
+use crate::{cycle::nth_state, Answer, Grid, Solution};
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        solve_part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        solve_part_two(input).into()
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum Rock {
+    Round,
+    Cube,
+}
+
+type Dish = Grid<Option<Rock>>;
+
+fn parse(input: &str) -> Dish {
+    Grid::parse(input, |ch| match ch {
+        b'.' => None,
+        b'O' => Some(Rock::Round),
+        b'#' => Some(Rock::Cube),
+        _ => unreachable!(),
+    })
+    .unwrap()
+}
+
+fn solve_part_one(input: &str) -> usize {
+    let dish = parse(input);
+    let num_rows = dish.height();
+
+    dish.columns()
+        .map(|col| {
+            izip!(col.copied(), (1..=num_rows).rev())
+                .filter_map(|(c, i)| Some((c?, i)))
+                .scan(num_rows + 1, |last_pos, (rock, i)| match rock {
+                    Rock::Round => {
+                        *last_pos -= 1;
+                        Some(*last_pos)
+                    }
+                    Rock::Cube => {
+                        *last_pos = i;
+                        Some(0)
+                    }
+                })
+                .sum::<usize>()
+        })
+        .sum()
+}
+
+fn solve_part_two(input: &str) -> usize {
+    let dish = nth_state(
+        parse(input),
+        |dish| {
+            let mut dish = dish.clone();
+            spin_cycle(&mut dish);
+            dish
+        },
+        1_000_000_000,
+    );
+
+    calculate_load(&dish)
+}
+
+fn spin_cycle(dish: &mut Dish) {
+    for _ in 0..4 {
+        turn(dish);
+    }
+}
+
+// Turns the dish clockwise and tilts it towards the east, so four turns tilt
+// it north, west, south and east.
+fn turn(dish: &mut Dish) {
+    *dish = dish.rotated_clockwise();
+
+    let n = dish.width();
+    for y in 0..dish.height() {
+        let row = dish.row_mut(y);
+        let mut last_pos = n;
+        for x in (0..n).rev() {
+            match row[x] {
+                None => (),
+                Some(Rock::Round) => {
+                    last_pos -= 1;
+                    row[x] = None;
+                    row[last_pos] = Some(Rock::Round);
+                }
+                Some(Rock::Cube) => {
+                    last_pos = x;
+                }
+            }
+        }
+    }
+}
+
+fn calculate_load(dish: &Dish) -> usize {
+    izip!(dish.rows(), (1..=dish.height()).rev())
+        .map(|(col, val)| {
+            val * (col
+                .iter()
+                .filter(|c| matches!(c, Some(Rock::Round)))
+                .count())
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_known_answers;
+
+    #[test]
+    fn known_answers() {
+        assert_known_answers::<Day14>();
+    }
+}
@@ -1,25 +1,30 @@
-#![feature(linked_list_cursors)]
-use std::{collections::LinkedList, u8};
+use std::collections::LinkedList;
 
-use aoc::read_file_input;
+use crate::{Answer, Solution};
 
-fn main() {
-    let res = solve(read_file_input("15.txt".to_string()));
+pub struct Day15;
 
-    println!("{}", res.0);
-    println!("{}", res.1);
-}
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Input<'a> = &'a str;
 
-fn solve(input: String) -> (u32, u32) {
-    let p1 = input
-        .split(",")
-        .map(|ch| hash(ch.as_bytes()))
-        .map(u32::from)
-        .sum();
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+    }
 
-    let p2 = calculate_total_focusing_power(&input);
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        input
+            .split(',')
+            .map(|ch| hash(ch.as_bytes()))
+            .map(u32::from)
+            .sum::<u32>()
+            .into()
+    }
 
-    (p1, p2)
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        calculate_total_focusing_power(input).into()
+    }
 }
 
 fn hash(ch: &[u8]) -> u8 {
@@ -33,7 +38,7 @@ fn calculate_total_focusing_power(input: &str) -> u32 {
     let mut boxes = vec![LinkedList::new(); 256];
 
     // Pack the lenses into the boxes.
-    input.split(",").for_each(|ch| match ch.as_bytes() {
+    input.split(',').for_each(|ch| match ch.as_bytes() {
         [box_name @ .., b'=', value @ b'0'..=b'9'] => {
             let box_id = hash(box_name) as usize;
             let box_value = value - b'0';
@@ -75,21 +80,3 @@ fn calculate_total_focusing_power(input: &str) -> u32 {
         })
         .sum()
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use aoc::read_test_file_input;
-
-    #[test]
-    fn test_solve_one() {
-        let res = solve(read_test_file_input("15_one.txt".to_string()));
-        assert_eq!(res.0, 1320);
-    }
-
-    #[test]
-    fn test_solve_two() {
-        let res = solve(read_test_file_input("15_one.txt".to_string()));
-        assert_eq!(res.1, 145);
-    }
-}
@@ -0,0 +1,122 @@
+use itertools::Itertools;
+use rayon::iter::IntoParallelIterator;
+use rayon::prelude::*;
+use std::mem;
+
+use crate::{geometry::Direction4, Answer, Grid, Solution};
+
+// Whether a beam passed through a tile, for each direction it can head in.
+#[derive(Clone, Copy, Default)]
+struct Visited([bool; 4]);
+
+impl Visited {
+    fn visit_dir(&mut self, dir: Direction4) -> bool {
+        mem::replace(&mut self.0[dir as usize], true)
+    }
+
+    fn is_energized(&self) -> bool {
+        self.0.contains(&true)
+    }
+}
+
+fn parse(input: &str) -> Grid<u8> {
+    Grid::parse(input, |tile| tile).unwrap()
+}
+
+fn calculate_energy(grid: &Grid<u8>, start: (usize, usize, Direction4)) -> u32 {
+    let (width, height) = (grid.width(), grid.height());
+    let mut cursors = vec![start];
+    let mut result = Grid::new(width, height, Visited::default());
+    while let Some((x, y, dir)) = cursors.pop() {
+        if result[(x, y)].visit_dir(dir) {
+            continue;
+        }
+
+        match (grid[(x, y)], dir) {
+            (b'.' | b'-', Direction4::Right)
+            | (b'/', Direction4::Up)
+            | (b'\\', Direction4::Down)
+                if x < width - 1 =>
+            {
+                cursors.push((x + 1, y, Direction4::Right))
+            }
+            (b'.' | b'-', Direction4::Left)
+            | (b'/', Direction4::Down)
+            | (b'\\', Direction4::Up)
+                if x > 0 =>
+            {
+                cursors.push((x - 1, y, Direction4::Left))
+            }
+            (b'.' | b'|', Direction4::Down)
+            | (b'/', Direction4::Left)
+            | (b'\\', Direction4::Right)
+                if y < height - 1 =>
+            {
+                cursors.push((x, y + 1, Direction4::Down))
+            }
+            (b'.' | b'|', Direction4::Up)
+            | (b'/', Direction4::Right)
+            | (b'\\', Direction4::Left)
+                if y > 0 =>
+            {
+                cursors.push((x, y - 1, Direction4::Up))
+            }
+            (b'-', Direction4::Up | Direction4::Down) => {
+                if x > 0 {
+                    cursors.push((x - 1, y, Direction4::Left));
+                }
+                if x < width - 1 {
+                    cursors.push((x + 1, y, Direction4::Right));
+                }
+            }
+            (b'|', Direction4::Left | Direction4::Right) => {
+                if y > 0 {
+                    cursors.push((x, y - 1, Direction4::Up));
+                }
+                if y < height - 1 {
+                    cursors.push((x, y + 1, Direction4::Down));
+                }
+            }
+            _ => (),
+        }
+    }
+
+    result
+        .iter()
+        .filter(|(_, visited)| visited.is_energized())
+        .count() as u32
+}
+
+fn calculate_most_energized_tiles(grid: &Grid<u8>) -> u32 {
+    let (width, height) = (grid.width(), grid.height());
+    (0..width)
+        .map(|x| (x, 0, Direction4::Down))
+        .chain((0..height).map(|y| (0, y, Direction4::Right)))
+        .chain((0..width).map(|x| (x, height - 1, Direction4::Up)))
+        .chain((0..height).map(|y| (width - 1, y, Direction4::Left)))
+        .collect_vec()
+        .into_par_iter()
+        .map(|start| calculate_energy(grid, start))
+        .max()
+        .unwrap()
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Input<'a> = Grid<u8>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        calculate_energy(input, (0, 0, Direction4::Right)).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        calculate_most_energized_tiles(input).into()
+    }
+}
@@ -0,0 +1,82 @@
+use crate::{
+    geometry::{Direction4, Point2},
+    search::dijkstra,
+    Answer, Grid, Solution,
+};
+
+// A crucible at a block, the way it's heading and how many blocks it has
+// moved that way in a row.
+type State = (Point2<usize>, Direction4, u8);
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+
+    type Input<'a> = Grid<u64>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        Grid::parse(input, |c| (c - b'0') as u64).unwrap()
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        least_heat_loss(input, &[Direction4::Right, Direction4::Down], 1, 3)
+            .unwrap()
+            .into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        least_heat_loss(input, &[Direction4::Right, Direction4::Down], 4, 10)
+            .unwrap()
+            .into()
+    }
+}
+
+// The least heat lost getting a crucible that sets off in one of `headings`
+// from the top left to the bottom right, when it has to move at least
+// `min_run` and at most `max_run` blocks in a straight line before turning or
+// stopping.
+fn least_heat_loss(
+    city: &Grid<u64>,
+    headings: &[Direction4],
+    min_run: u8,
+    max_run: u8,
+) -> Option<u64> {
+    let start = Point2::new(0, 0);
+    let goal = Point2::new(city.width() - 1, city.height() - 1);
+
+    let neighbours = |&(position, direction, run): &State| {
+        Direction4::ALL
+            .into_iter()
+            .filter(move |&dir| {
+                if dir == direction {
+                    run < max_run
+                } else {
+                    dir != direction.opposite() && run >= min_run
+                }
+            })
+            .filter_map(move |dir| {
+                let next = position.step(dir).filter(|&p| city.contains(p.into()))?;
+                let run = if dir == direction { run + 1 } else { 1 };
+                Some(((next, dir, run), city[next.into()]))
+            })
+    };
+
+    dijkstra(
+        headings.iter().map(|&heading| (start, heading, 0)),
+        neighbours,
+        |&(position, _, run)| position == goal && run >= min_run,
+    )
+    .goal_cost()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_known_answers;
+
+    #[test]
+    fn known_answers() {
+        assert_known_answers::<Day17>();
+    }
+}
@@ -1,17 +1,23 @@
-use aoc::read_file_input;
+use crate::{Answer, Solution};
 
-fn main() {
-    let res = solve(read_file_input("18.txt".to_string()));
+pub struct Day18;
 
-    println!("{}", res.0);
-    println!("{}", res.1);
-}
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+    }
 
-fn solve(input: String) -> (u64, u64) {
-    let p1 = solve_part_one(&input);
-    let p2 = solve_part_two(&input);
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        solve_part_one(input).into()
+    }
 
-    (p1, p2)
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        solve_part_two(input).into()
+    }
 }
 
 fn solve_part_one(input: &str) -> u64 {
@@ -84,21 +90,3 @@ fn calculate_area(visited: &[[i64; 2]], exterior: u64) -> u64 {
 
     area as u64 + 1 + exterior / 2
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use aoc::read_test_file_input;
-
-    #[test]
-    fn test_solve_one() {
-        let res = solve(read_test_file_input("18_one.txt".to_string()));
-        assert_eq!(res.0, 62);
-    }
-
-    #[test]
-    fn test_solve_two() {
-        let res = solve(read_test_file_input("18_one.txt".to_string()));
-        assert_eq!(res.1, 952_408_144_115);
-    }
-}
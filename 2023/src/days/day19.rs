@@ -1,21 +1,28 @@
-use aoc::read_file_input;
 use bstr::ByteSlice;
 use fnv::FnvHashMap;
 use itertools::Itertools;
 use std::iter;
 
-fn main() {
-    let res = solve(read_file_input("19.txt".to_string()));
+use crate::{interval::Interval, Answer, Solution};
 
-    println!("{}", res.0);
-    println!("{}", res.1);
-}
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+
+    type Input<'a> = &'a [u8];
 
-fn solve(input: String) -> (u32, u64) {
-    let p1 = solve_part_one(&input.as_bytes());
-    let p2 = solve_part_two(&input.as_bytes());
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.as_bytes()
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        solve_part_one(input).into()
+    }
 
-    (p1, p2)
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        solve_part_two(input).into()
+    }
 }
 
 #[derive(Clone, Copy, Default)]
@@ -47,37 +54,17 @@ struct Rule {
     operation: Operation,
 }
 
-#[derive(Clone, Copy)]
-struct ValueRange {
-    min: u16,
-    max: u16,
-}
-
-impl ValueRange {
-    fn new(min: u16, max: u16) -> Self {
-        Self { min, max }
-    }
-
-    fn combination_count(&self) -> u64 {
-        (self.max + 1 - self.min) as u64
-    }
-
-    fn split(&self, threshold: u16, operation: Operation) -> (ValueRange, ValueRange) {
-        match operation {
-            Operation::GreaterThan => (
-                ValueRange::new(self.min, threshold),
-                ValueRange::new(threshold + 1, self.max),
-            ),
-            Operation::LessThan => (
-                ValueRange::new(threshold, self.max),
-                ValueRange::new(self.min, threshold - 1),
-            ),
+// Splits the values of `range` into the ones the rule doesn't match and the
+// ones it does.
+fn split(range: Interval, threshold: u16, operation: Operation) -> (Interval, Interval) {
+    let threshold = threshold as i64;
+    match operation {
+        Operation::GreaterThan => range.split_at(threshold + 1),
+        Operation::LessThan => {
+            let (matched, rest) = range.split_at(threshold);
+            (rest, matched)
         }
     }
-
-    fn is_empty(&self) -> bool {
-        self.min > self.max
-    }
 }
 
 fn pad_rule(rule: &[u8]) -> [u8; 3] {
@@ -213,17 +200,17 @@ fn solve_part_one(input: &[u8]) -> u32 {
     valid
 }
 
-fn solve_part_two(input: &[u8]) -> u64 {
+fn solve_part_two(input: &[u8]) -> i64 {
     let (rules, _) = input.split_once_str("\n\n").unwrap();
     let rules = parse_rules(rules);
 
     let mut queue = vec![(
-        [b' ', b'i', b'n'],
+        *b" in",
         [
-            ValueRange::new(1, 4000),
-            ValueRange::new(1, 4000),
-            ValueRange::new(1, 4000),
-            ValueRange::new(1, 4000),
+            Interval::closed(1, 4000),
+            Interval::closed(1, 4000),
+            Interval::closed(1, 4000),
+            Interval::closed(1, 4000),
         ],
     )];
     let mut out = 0;
@@ -239,16 +226,13 @@ fn solve_part_two(input: &[u8]) -> u64 {
             (
                 bounds[rule.attr as usize],
                 rule_match_bounds[rule.attr as usize],
-            ) = bounds[rule.attr as usize].split(rule.threshold, rule.operation);
+            ) = split(bounds[rule.attr as usize], rule.threshold, rule.operation);
 
             if !rule_match_bounds[rule.attr as usize].is_empty() {
                 match rule.target {
                     Target::Reject => (),
                     Target::Accept => {
-                        out += rule_match_bounds
-                            .iter()
-                            .map(ValueRange::combination_count)
-                            .product::<u64>();
+                        out += rule_match_bounds.iter().map(Interval::len).product::<i64>();
                         continue;
                     }
                     Target::Rule(target) => queue.push((target, rule_match_bounds)),
@@ -263,10 +247,7 @@ fn solve_part_two(input: &[u8]) -> u64 {
         match cursor.default_target {
             Target::Reject => continue,
             Target::Accept => {
-                out += bounds
-                    .iter()
-                    .map(ValueRange::combination_count)
-                    .product::<u64>();
+                out += bounds.iter().map(Interval::len).product::<i64>();
             }
             Target::Rule(target) => {
                 queue.push((target, bounds));
@@ -280,29 +261,10 @@ fn solve_part_two(input: &[u8]) -> u64 {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::read_test_file_input;
-
-    #[test]
-    fn test_solve_one() {
-        let (res, _) = solve(read_test_file_input("19_one.txt".to_string()));
-        assert_eq!(res, 19114);
-    }
-
-    #[test]
-    fn test_solve_two() {
-        let (_, res) = solve(read_test_file_input("19_one.txt".to_string()));
-        assert_eq!(res, 167_409_079_868_000);
-    }
-
-    #[test]
-    fn actual_solve_one() {
-        let (res, _) = solve(read_file_input("19.txt".to_string()));
-        assert_eq!(res, 367602);
-    }
+    use crate::assert_known_answers;
 
     #[test]
-    fn actual_solve_two() {
-        let (_, res) = solve(read_file_input("19.txt".to_string()));
-        assert_eq!(res, 125_317_461_667_458);
+    fn known_answers() {
+        assert_known_answers::<Day19>();
     }
 }
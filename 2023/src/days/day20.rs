@@ -1,9 +1,10 @@
 use std::collections::VecDeque;
 
-use aoc::read_file_input;
 use fnv::FnvHashMap;
 use itertools::Itertools;
 
+use crate::{math::lcm_all, Answer, Solution};
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 enum Pulse {
     High,
@@ -22,21 +23,27 @@ struct DestinationModule<'a> {
     outputs: Vec<&'a str>,
 }
 
-fn main() {
-    let res = solve(read_file_input("20.txt".to_string()));
+pub struct Day20;
 
-    println!("{}", res.0);
-    println!("{}", res.1);
-}
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+
+    type Input<'a> = &'a str;
 
-fn solve(input: String) -> (u32, u64) {
-    let p1 = solve_part_one(&input);
-    let p2 = solve_part_two(&input);
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        solve_part_one(input).into()
+    }
 
-    (p1, p2)
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        solve_part_two(input).into()
+    }
 }
 
-fn parse(input: &str) -> (Vec<&str>, FnvHashMap<&str, DestinationModule>) {
+fn parse(input: &str) -> (Vec<&str>, FnvHashMap<&str, DestinationModule<'_>>) {
     let broadcaster_targets = input
         .lines()
         .find_map(|line| {
@@ -52,7 +59,7 @@ fn parse(input: &str) -> (Vec<&str>, FnvHashMap<&str, DestinationModule>) {
             let (name, outputs) = line[1..].split_once(" -> ").unwrap();
             let outputs = outputs.split(", ").collect_vec();
 
-            let kind = match line.chars().nth(0).unwrap() {
+            let kind = match line.chars().next().unwrap() {
                 '%' => DestinationModuleKind::FlipFlop { off: true },
                 '&' => DestinationModuleKind::Conjunction {
                     last_pulses: vec![],
@@ -150,27 +157,12 @@ fn solve_part_one(input: &str) -> u32 {
     output_low * output_high
 }
 
-// greatest common divisor
-fn gcd(mut a: u64, mut b: u64) -> u64 {
-    while b > 0 {
-        let remainder = a % b;
-        a = b;
-        b = remainder;
-    }
-    a
-}
-
-// least common multiple
-fn lcm(a: u64, b: u64) -> u64 {
-    a * b / gcd(a, b)
-}
-
 fn solve_part_two(input: &str) -> u64 {
     let (broadcaster_targets, mut rules) = parse(input);
 
     let rx_parent = rules
         .iter()
-        .find_map(|(name, module)| module.outputs.contains(&&"rx").then_some(*name))
+        .find_map(|(name, module)| module.outputs.contains(&"rx").then_some(*name))
         .unwrap();
 
     let rx_parent_inputs = {
@@ -206,39 +198,16 @@ fn solve_part_two(input: &str) -> u64 {
         it += 1
     }
 
-    loop_index
-        .into_iter()
-        .map(Option::unwrap)
-        .reduce(lcm)
-        .unwrap()
+    lcm_all(loop_index.into_iter().map(Option::unwrap))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aoc::read_test_file_input;
-
-    #[test]
-    fn test_solve_one_a() {
-        let res = solve_part_one(&read_test_file_input("20_one_a.txt".to_string()));
-        assert_eq!(res, 32_000_000);
-    }
-
-    #[test]
-    fn test_solve_one_b() {
-        let res = solve_part_one(&read_test_file_input("20_one_b.txt".to_string()));
-        assert_eq!(res, 11_687_500);
-    }
-
-    #[test]
-    fn actual_solve_one() {
-        let res = solve_part_one(&read_file_input("20.txt".to_string()));
-        assert_eq!(res, 703_315_117);
-    }
+    use crate::assert_known_answers;
 
     #[test]
-    fn actual_solve_two() {
-        let res = solve_part_two(&read_file_input("20.txt".to_string()));
-        assert_eq!(res, 230_402_300_925_361);
+    fn known_answers() {
+        assert_known_answers::<Day20>();
     }
 }
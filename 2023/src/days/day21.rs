@@ -0,0 +1,305 @@
+use std::{error::Error, fmt, mem};
+
+use crate::{math, Answer, Grid, Params, Solution};
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        Self::part_one_with(input, &Params::default())
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        Self::part_two_with(input, &Params::default())
+    }
+
+    fn part_one_with(input: &Self::Input<'_>, params: &Params) -> Answer {
+        solve(input, params.get_or("steps", 64))
+    }
+
+    fn part_two_with(input: &Self::Input<'_>, params: &Params) -> Answer {
+        solve(input, params.get_or("steps", 26_501_365))
+    }
+}
+
+fn solve(input: &str, steps: usize) -> Answer {
+    let (map, start) = parse(input);
+    match reachable_on_infinite_grid(&map, start, steps) {
+        Ok(count) => count.into(),
+        Err(err) => panic!("{}", err),
+    }
+}
+
+// Returns the map, with `true` for garden plots, and the start position.
+fn parse(input: &str) -> (Grid<bool>, (usize, usize)) {
+    let map = Grid::parse(input, |tile| match tile {
+        b'.' | b'S' | b'#' => tile,
+        _ => panic!("unexpected tile {:?}", char::from(tile)),
+    })
+    .unwrap();
+    let start_pos = map.find(&b'S').unwrap();
+
+    (map.map(|&tile| tile != b'#'), start_pos)
+}
+
+/// The most steps walked one by one, which takes a few megabytes of bits and
+/// well under a second.
+pub const MAX_WALK: usize = 2_000;
+
+// How many counts a period apart the quadratic has to go through: three fix
+// it and the rest check it.
+const SAMPLES: usize = 7;
+
+/// A map on which the plots reached don't grow quadratically within
+/// [`MAX_WALK`] steps, asked for more steps than that.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TooManySteps {
+    pub steps: usize,
+}
+
+impl fmt::Display for TooManySteps {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "the plots reached don't grow quadratically within {} steps, \
+             and {} steps are too many to walk",
+            MAX_WALK, self.steps
+        )
+    }
+}
+
+impl Error for TooManySteps {}
+
+/// The number of garden plots that can be reached in exactly `steps` steps
+/// from `start`, on the map repeated infinitely in every direction.
+///
+/// The plots reached spread over the copies of the map in a growing diamond,
+/// so for step counts a whole period of the map apart, their number
+/// eventually grows quadratically. When it does, a few sampled counts give
+/// the rest. Otherwise the steps are walked one by one, which is only done
+/// for up to [`MAX_WALK`] steps, so a count further out than that on such a
+/// map is a [`TooManySteps`] error.
+pub fn reachable_on_infinite_grid(
+    map: &Grid<bool>,
+    start: (usize, usize),
+    steps: usize,
+) -> Result<u64, TooManySteps> {
+    let period = math::lcm(map.width() as u64, map.height() as u64) as usize;
+    let offset = steps % period;
+    let mut counts = Vec::new();
+
+    // The samples starting at `k`, where the quadratic has to hold over all
+    // of them.
+    for k in 0.. {
+        let last = offset + (k + SAMPLES - 1) * period;
+        if last >= steps || last > MAX_WALK {
+            break;
+        }
+        if counts.len() <= last {
+            // After the first try, walk twice as far as needed, so that the
+            // walks thrown away take no longer than the last one.
+            let limit = if counts.is_empty() { last } else { 2 * last };
+            counts = reachable_counts(map, start, limit.min(steps).min(MAX_WALK));
+        }
+
+        let samples: Vec<i128> = (k..k + SAMPLES)
+            .map(|i| counts[offset + i * period].into())
+            .collect();
+        if let Some(count) = extrapolate(&samples, ((steps - offset) / period - k) as i128) {
+            return Ok(count as u64);
+        }
+    }
+
+    if steps > MAX_WALK {
+        return Err(TooManySteps { steps });
+    }
+    if counts.len() <= steps {
+        counts = reachable_counts(map, start, steps);
+    }
+    Ok(counts[steps])
+}
+
+// Continues the quadratic through the first three of `samples` to the value
+// at `n`, if the remaining samples are on it too.
+fn extrapolate(samples: &[i128], n: i128) -> Option<i128> {
+    let differences: Vec<i128> = samples.windows(2).map(|w| w[1] - w[0]).collect();
+    let second = differences[1] - differences[0];
+    if differences.windows(2).any(|w| w[1] - w[0] != second) {
+        return None;
+    }
+
+    Some(samples[0] + n * differences[0] + n * (n - 1) / 2 * second)
+}
+
+// The number of plots that can be reached in exactly `n` steps from `start`,
+// for every `n` up to `limit`, in a single walk.
+//
+// The walk covers enough copies of the map around the start that `limit`
+// steps can't leave them, as rows of bits. A step moves every bit of a row
+// left and right with shifts and every row up and down, and keeps the bits on
+// plots, so after `n` steps the bits set are exactly the plots reachable in
+// `n` steps.
+fn reachable_counts(map: &Grid<bool>, (x, y): (usize, usize), limit: usize) -> Vec<u64> {
+    let (tiles_x, tiles_y) = (limit / map.width() + 1, limit / map.height() + 1);
+    let width = (2 * tiles_x + 1) * map.width();
+    let height = (2 * tiles_y + 1) * map.height();
+    let (x, y) = (tiles_x * map.width() + x, tiles_y * map.height() + y);
+
+    // Each row of the map repeated across, then copied down.
+    let mut plots = Bits::new(width, height);
+    for row in 0..map.height() {
+        for (column, _) in map.row(row).iter().enumerate().filter(|(_, &plot)| plot) {
+            for tile in 0..2 * tiles_x + 1 {
+                plots.set(tile * map.width() + column, row);
+            }
+        }
+    }
+    let tile_words = map.height() * plots.words_per_row;
+    for tile in 1..2 * tiles_y + 1 {
+        plots.words.copy_within(..tile_words, tile * tile_words);
+    }
+
+    let mut current = Bits::new(width, height);
+    current.set(x, y);
+    let mut next = Bits::new(width, height);
+    let mut counts = vec![1];
+
+    for n in 1..=limit {
+        // Only the rows within `n` steps of the start can be reached, and
+        // the rows around them are still empty.
+        for row in y - n..=y + n {
+            let (above, middle, below) =
+                (current.row(row - 1), current.row(row), current.row(row + 1));
+
+            for (i, word) in next.row_mut(row).iter_mut().enumerate() {
+                let mut reached = middle[i] << 1 | middle[i] >> 1;
+                if i > 0 {
+                    reached |= middle[i - 1] >> 63;
+                }
+                if i + 1 < middle.len() {
+                    reached |= middle[i + 1] << 63;
+                }
+                *word = (reached | above[i] | below[i]) & plots.row(row)[i];
+            }
+        }
+
+        mem::swap(&mut current, &mut next);
+        counts.push(current.count());
+    }
+
+    counts
+}
+
+// A bit per cell of a grid, 64 cells of a row to a word.
+struct Bits {
+    words_per_row: usize,
+    words: Vec<u64>,
+}
+
+impl Bits {
+    fn new(width: usize, height: usize) -> Self {
+        let words_per_row = width.div_ceil(64);
+        Bits {
+            words_per_row,
+            words: vec![0; words_per_row * height],
+        }
+    }
+
+    fn set(&mut self, x: usize, y: usize) {
+        self.words[y * self.words_per_row + x / 64] |= 1 << (x % 64);
+    }
+
+    fn row(&self, y: usize) -> &[u64] {
+        &self.words[y * self.words_per_row..(y + 1) * self.words_per_row]
+    }
+
+    fn row_mut(&mut self, y: usize) -> &mut [u64] {
+        &mut self.words[y * self.words_per_row..(y + 1) * self.words_per_row]
+    }
+
+    fn count(&self) -> u64 {
+        self.words
+            .iter()
+            .map(|word| u64::from(word.count_ones()))
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::{
+        assert_known_answers,
+        geometry::{Direction4, Point2},
+    };
+
+    #[test]
+    fn known_answers() {
+        assert_known_answers::<Day21>();
+    }
+
+    // Walks the infinite map a step at a time with the set of plots reached,
+    // as a reference for small step counts.
+    fn reachable_by_walking(map: &Grid<bool>, (x, y): (usize, usize), steps: usize) -> u64 {
+        let (width, height) = (map.width() as isize, map.height() as isize);
+        let mut reached = HashSet::from([Point2::new(x as isize, y as isize)]);
+
+        for _ in 0..steps {
+            reached = reached
+                .into_iter()
+                .flat_map(|pos| Direction4::ALL.map(|dir| pos + dir.delta()))
+                .filter(|pos| {
+                    map[(
+                        pos.x.rem_euclid(width) as usize,
+                        pos.y.rem_euclid(height) as usize,
+                    )]
+                })
+                .collect();
+        }
+        reached.len() as u64
+    }
+
+    #[test]
+    fn test_reachable_counts() {
+        let (map, start) = parse(&crate::YEAR.read_example("21_one.txt").unwrap());
+        let counts = reachable_counts(&map, start, 60);
+
+        assert_eq!(counts.len(), 61);
+        assert_eq!((counts[6], counts[10], counts[50]), (16, 50, 1594));
+        for steps in [0, 1, 2, 11, 23, 37, 60] {
+            assert_eq!(counts[steps], reachable_by_walking(&map, start, steps));
+        }
+    }
+
+    #[test]
+    fn test_reachable_on_infinite_grid() {
+        let (map, start) = parse(&crate::YEAR.read_example("21_one.txt").unwrap());
+        let counts = reachable_counts(&map, start, 500);
+
+        for steps in (0..100).chain([200, 301, 499]) {
+            assert_eq!(
+                reachable_on_infinite_grid(&map, start, steps),
+                Ok(counts[steps]),
+                "{} steps",
+                steps
+            );
+        }
+    }
+
+    #[test]
+    fn test_extrapolate() {
+        let squares = [4, 9, 16, 25, 36];
+        assert_eq!(extrapolate(&squares, 8), Some(100));
+        assert_eq!(extrapolate(&[4, 9, 16, 25, 37], 8), None);
+    }
+}
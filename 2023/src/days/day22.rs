@@ -1,32 +1,31 @@
 use std::collections::{HashMap, HashSet};
 
-use aoc::read_file_input;
-
-#[derive(Debug, Clone)]
-struct Position {
-    x: usize,
-    y: usize,
-    z: usize,
-}
+use crate::{geometry::Point3, Answer, Solution};
 
 #[derive(Debug, Clone)]
 struct Brick {
-    start: Position,
-    end: Position,
+    start: Point3<usize>,
+    end: Point3<usize>,
 }
 
-fn main() {
-    let res = solve(read_file_input("22.txt".to_string()));
+pub struct Day22;
 
-    println!("{}", res.0);
-    println!("{}", res.1);
-}
+impl Solution for Day22 {
+    const DAY: u8 = 22;
 
-fn solve(input: String) -> (usize, usize) {
-    let p1 = solve_part_one(&input);
-    let p2 = solve_part_two(&input);
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        solve_part_one(input).into()
+    }
 
-    (p1, p2)
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        solve_part_two(input).into()
+    }
 }
 
 fn solve_part_one(input: &str) -> usize {
@@ -65,7 +64,7 @@ fn parse(input: &str) -> Vec<Brick> {
             let mut line = line.split('~').map(|pos| {
                 let mut pos = pos.split(',').map(|coord| coord.parse().unwrap()); // x,y,z
 
-                Position {
+                Point3 {
                     x: pos.next().unwrap(),
                     y: pos.next().unwrap(),
                     z: pos.next().unwrap(),
@@ -137,21 +136,3 @@ fn fall_into_place(
     }
     count.len()
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use aoc::read_test_file_input;
-
-    #[test]
-    fn test_solve_one() {
-        let res = solve(read_test_file_input("22_one.txt".to_string()));
-        assert_eq!(res.0, 5);
-    }
-
-    #[test]
-    fn test_solve_two() {
-        let res = solve(read_test_file_input("22_one.txt".to_string()));
-        assert_eq!(res.1, 7);
-    }
-}
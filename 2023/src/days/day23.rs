@@ -0,0 +1,401 @@
+use petgraph::prelude::*;
+use rayon::prelude::*;
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use crate::{
+    geometry::{Direction4, Point2},
+    Answer, Grid, Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Heading {
+    Forward,
+    Backward,
+    Both,
+    Impassable,
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+
+    type Input<'a> = Grid<u8>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        solve_part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        solve_part_two(input).into()
+    }
+}
+
+fn parse(input: &str) -> Grid<u8> {
+    Grid::parse(input, |tile| tile).unwrap()
+}
+
+fn solve_part_one(map: &Grid<u8>) -> usize {
+    longest_hike(map, false).length
+}
+
+fn solve_part_two(map: &Grid<u8>) -> usize {
+    longest_hike(map, true).length
+}
+
+/// The junctions of the longest hike from the start to the end, in order, and
+/// its length in steps.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hike {
+    pub route: Vec<Point2<usize>>,
+    pub length: usize,
+}
+
+/// The longest hike through `map` that never steps on the same tile twice,
+/// going only downhill on slopes unless `ignore_slopes`.
+pub fn longest_hike(map: &Grid<u8>, ignore_slopes: bool) -> Hike {
+    let start = Point2::new(1, 0);
+    let end = Point2::new(map.width() - 2, map.height() - 1);
+    let graph = junction_graph(map, ignore_slopes);
+
+    let trails = Trails::new(&graph, start, end);
+    let (length, route) = trails.longest().expect("no hike reaches the end");
+
+    Hike {
+        route: route.into_iter().map(|node| trails.nodes[node]).collect(),
+        length,
+    }
+}
+
+// The start, the end and the junctions between, with an edge for every trail
+// between two of them that can be walked in that direction, weighted by its
+// length.
+fn junction_graph(map: &Grid<u8>, ignore_slopes: bool) -> DiGraphMap<Point2<usize>, usize> {
+    let mut graph = DiGraphMap::new();
+    let start = Point2::new(1, 0);
+    let end = Point2::new(map.width() - 2, map.height() - 1);
+
+    let mut queue = VecDeque::new();
+    queue.push_back((
+        Point2::new(1, 1), // Position
+        start,             // Previous position
+        1,                 // Path length
+        Heading::Both,     // Heading (is it directed?)
+        Direction4::Down,  // Direction
+    ));
+
+    while let Some((position, mut previous, mut path_len, heading, direction)) = queue.pop_front() {
+        if position == end {
+            graph.add_edge(previous, position, path_len);
+            continue;
+        }
+
+        if graph.contains_node(position) {
+            if ignore_slopes || matches!(heading, Heading::Both | Heading::Forward) {
+                graph.add_edge(previous, position, path_len);
+            }
+
+            if ignore_slopes || matches!(heading, Heading::Both | Heading::Backward) {
+                graph.add_edge(position, previous, path_len);
+            }
+
+            continue;
+        }
+
+        let mut next = Vec::with_capacity(3);
+
+        for dir in [
+            Direction4::Up,
+            Direction4::Down,
+            Direction4::Left,
+            Direction4::Right,
+        ] {
+            if dir == direction.opposite() {
+                continue;
+            }
+            let Some((next_pos, &tile)) = position
+                .step(dir)
+                .and_then(|pos| Some((pos, map.get(pos.into())?)))
+                .filter(|&(_, &tile)| tile != b'#')
+            else {
+                continue;
+            };
+
+            let heading = match tile {
+                tile if tile == slope(dir) => Heading::Forward,
+                tile if tile == slope(dir.opposite()) => Heading::Backward,
+                _ => heading,
+            };
+            next.push((next_pos, heading, dir));
+        }
+
+        // Junction point
+        if next.len() >= 2 && position != Point2::new(1, 1) {
+            if ignore_slopes || matches!(heading, Heading::Both | Heading::Forward) {
+                graph.add_edge(previous, position, path_len);
+            }
+
+            if ignore_slopes || matches!(heading, Heading::Both | Heading::Backward) {
+                graph.add_edge(position, previous, path_len);
+            }
+
+            path_len = 0;
+            previous = position;
+        }
+
+        // increment the path length
+        path_len += 1;
+
+        for (position, mut is_directed, direction) in next {
+            if heading == Heading::Impassable || heading != Heading::Both && is_directed != heading
+            {
+                is_directed = Heading::Impassable;
+            }
+            queue.push_back((position, previous, path_len, is_directed, direction));
+        }
+    }
+
+    graph
+}
+
+// The junction graph indexed for the search for the longest simple path, so
+// that the junctions on a path fit in the bits of a `u64`.
+//
+// That limits the graph to 64 junctions, the start and the end included. The
+// hiking maps have about 36, as every junction is a crossing of trails a few
+// dozen tiles long, so a map needs several times the usual area to reach the
+// limit, and `new` panics with the count for such a map.
+struct Trails {
+    nodes: Vec<Point2<usize>>,
+    // The trails out of each junction, to the junction and their length.
+    edges: Vec<Vec<(usize, usize)>>,
+    // The longest trail into each junction, which bounds how much longer a
+    // path can get by going through it.
+    longest_in: Vec<usize>,
+    start: usize,
+    end: usize,
+}
+
+// A junction on the path of the search, the next of its trails to try and
+// the length of the trail that led to it.
+struct Frame {
+    node: usize,
+    next_edge: usize,
+    length_in: usize,
+}
+
+impl Trails {
+    fn new(
+        graph: &DiGraphMap<Point2<usize>, usize>,
+        start: Point2<usize>,
+        end: Point2<usize>,
+    ) -> Self {
+        let nodes: Vec<Point2<usize>> = graph.nodes().collect();
+        assert!(
+            nodes.len() <= 64,
+            "{} junctions, but the search keeps track of at most 64",
+            nodes.len()
+        );
+        let index: HashMap<_, _> = nodes.iter().enumerate().map(|(i, &n)| (n, i)).collect();
+
+        let mut edges: Vec<Vec<(usize, usize)>> = nodes
+            .iter()
+            .map(|&node| {
+                graph
+                    .edges(node)
+                    .map(|(_, to, &length)| (index[&to], length))
+                    .collect()
+            })
+            .collect();
+        let mut longest_in = vec![0; nodes.len()];
+        for (_, to, &length) in graph.all_edges() {
+            longest_in[index[&to]] = longest_in[index[&to]].max(length);
+        }
+
+        // A path that leaves the only junction before the end can't come
+        // back to it, so from there it has to go to the end.
+        let end = index[&end];
+        let before_end: Vec<usize> = (0..nodes.len())
+            .filter(|&node| edges[node].iter().any(|&(to, _)| to == end))
+            .collect();
+        if let [last] = before_end[..] {
+            edges[last].retain(|&(to, _)| to == end);
+        }
+
+        Trails {
+            nodes,
+            edges,
+            longest_in,
+            start: index[&start],
+            end,
+        }
+    }
+
+    // The longest simple path from the start to the end and the junctions on
+    // it, if there is any path.
+    //
+    // The first few junctions of every path are laid out up front, and the
+    // paths that continue from each of them are searched in parallel, sharing
+    // the longest length found so far to cut off paths that can't beat it.
+    fn longest(&self) -> Option<(usize, Vec<usize>)> {
+        let best = AtomicUsize::new(0);
+
+        self.prefixes(6)
+            .into_par_iter()
+            .filter_map(|(prefix, length)| self.search(prefix, length, &best))
+            .max_by_key(|&(length, _)| length)
+    }
+
+    // Every path of `depth` trails from the start, or fewer when it reaches
+    // the end or a dead end first, with its length.
+    fn prefixes(&self, depth: usize) -> Vec<(Vec<usize>, usize)> {
+        let mut prefixes = vec![(vec![self.start], 0)];
+
+        for _ in 0..depth {
+            prefixes = prefixes
+                .into_iter()
+                .flat_map(|(path, length)| {
+                    let last = *path.last().unwrap();
+                    let next: Vec<_> = self.edges[last]
+                        .iter()
+                        .filter(|(to, _)| !path.contains(to))
+                        .map(|&(to, trail)| {
+                            let mut path = path.clone();
+                            path.push(to);
+                            (path, length + trail)
+                        })
+                        .collect();
+
+                    if last == self.end || next.is_empty() {
+                        vec![(path, length)]
+                    } else {
+                        next
+                    }
+                })
+                .collect();
+        }
+
+        prefixes
+    }
+
+    // The longest path to the end that starts with `prefix`, unless it's no
+    // longer than `best`, with an iterative depth-first search.
+    fn search(
+        &self,
+        prefix: Vec<usize>,
+        mut length: usize,
+        best: &AtomicUsize,
+    ) -> Option<(usize, Vec<usize>)> {
+        let mut visited = prefix
+            .iter()
+            .fold(0_u64, |visited, node| visited | 1 << node);
+        // The most the path can still grow by: a longest trail into every
+        // junction it hasn't been to yet.
+        let mut potential: usize = (0..self.nodes.len())
+            .filter(|node| visited & 1 << node == 0)
+            .map(|node| self.longest_in[node])
+            .sum();
+
+        let (&first, before) = prefix.split_last().unwrap();
+        let mut path = vec![Frame {
+            node: first,
+            next_edge: 0,
+            length_in: 0,
+        }];
+        let mut found = None;
+
+        while let Some(frame) = path.last_mut() {
+            let node = frame.node;
+            let next = match self.edges[node].get(frame.next_edge) {
+                Some(&edge) if node != self.end => edge,
+                _ => {
+                    if node == self.end && length > best.load(Ordering::Relaxed) {
+                        best.fetch_max(length, Ordering::Relaxed);
+                        let route = before.iter().copied().chain(path.iter().map(|f| f.node));
+                        found = Some((length, route.collect()));
+                    }
+
+                    // Back to the junction before. Undoing the first one
+                    // too is harmless, as the search ends with it.
+                    let frame = path.pop().unwrap();
+                    visited &= !(1 << frame.node);
+                    length -= frame.length_in;
+                    potential += self.longest_in[frame.node];
+                    continue;
+                }
+            };
+            frame.next_edge += 1;
+
+            let (to, trail) = next;
+            if visited & 1 << to != 0 {
+                continue;
+            }
+            // Only an unvisited junction still counts towards `potential`.
+            let reachable = length + trail + potential - self.longest_in[to];
+            if reachable > best.load(Ordering::Relaxed) {
+                visited |= 1 << to;
+                length += trail;
+                potential -= self.longest_in[to];
+                path.push(Frame {
+                    node: to,
+                    next_edge: 0,
+                    length_in: trail,
+                });
+            }
+        }
+
+        found
+    }
+}
+
+// The slope that can only be walked down heading in `direction`.
+fn slope(direction: Direction4) -> u8 {
+    match direction {
+        Direction4::Up => b'^',
+        Direction4::Right => b'>',
+        Direction4::Down => b'v',
+        Direction4::Left => b'<',
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_known_answers;
+
+    #[test]
+    fn known_answers() {
+        assert_known_answers::<Day23>();
+    }
+
+    #[test]
+    fn test_longest_hike() {
+        let map = parse(&crate::YEAR.read_example("23_one.txt").unwrap());
+
+        for (ignore_slopes, expected) in [(false, 94), (true, 154)] {
+            let hike = longest_hike(&map, ignore_slopes);
+            assert_eq!(hike.length, expected);
+
+            let graph = junction_graph(&map, ignore_slopes);
+            let walked: usize = hike
+                .route
+                .windows(2)
+                .map(|pair| graph.edge_weight(pair[0], pair[1]).unwrap())
+                .sum();
+            assert_eq!(walked, expected);
+            assert!(hike
+                .route
+                .iter()
+                .all(|&junction| map[junction.into()] != b'#'));
+            assert_eq!(hike.route.first(), Some(&Point2::new(1, 0)));
+            assert_eq!(hike.route.last(), Some(&Point2::new(21, 22)));
+        }
+    }
+}
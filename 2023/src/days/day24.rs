@@ -0,0 +1,373 @@
+// Part 1 was straight forward. Part2 was hard and I got a hint about z3 solver on reddit.
+// The rock and a hailstone collide when their relative position and velocity
+// are parallel, so (p - p_i) x (v - v_i) = 0 for every hailstone i. The
+// p x v term is the same for all of them, so subtracting the equations of two
+// hailstones leaves three linear ones, and two pairs give the six unknowns.
+// Those are solved with exact rationals, as eliminating them overflows i128.
+//
+// The z3 SMT solver used before is still there behind the `z3` feature, to
+// cross-check it. It needs `clang` and `cmake` to build.
+// See: https://crates.io/crates/z3
+// See: https://avigad.github.io/lamr/using_smt_solvers.html
+
+use std::ops::RangeInclusive;
+
+use itertools::Itertools;
+use num::{rational::Ratio, BigInt, BigRational, Signed, ToPrimitive, Zero};
+
+use crate::{geometry::Point3, Answer, Params, Solution};
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        Self::part_one_with(input, &Params::default())
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Answer {
+        solve_part_two(input).into()
+    }
+
+    // The test area spans from `min` to `max` on both the X and Y axes.
+    fn part_one_with(input: &Self::Input<'_>, params: &Params) -> Answer {
+        let min = params.get_or("min", 200_000_000_000_000_i64);
+        let max = params.get_or("max", 400_000_000_000_000_i64);
+        let area = TestArea {
+            x: min..=max,
+            y: min..=max,
+        };
+        solve_part_one(input, &area).into()
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Hailstone {
+    pos: Point3,
+    vel: Point3,
+}
+
+impl Hailstone {
+    fn new(px: i64, py: i64, pz: i64, vx: i64, vy: i64, vz: i64) -> Hailstone {
+        Hailstone {
+            pos: Point3::new(px, py, pz),
+            vel: Point3::new(vx, vy, vz),
+        }
+    }
+}
+
+fn parse(input: &str) -> Vec<Hailstone> {
+    input
+        .lines()
+        .map(|line| {
+            let line = line.split('@').collect::<Vec<&str>>();
+            let pos: Vec<i64> = line[0]
+                .split(',')
+                .map(|p| p.trim().parse().unwrap())
+                .collect();
+            let vel: Vec<i64> = line[1]
+                .split(',')
+                .map(|v| v.trim().parse().unwrap())
+                .collect();
+            Hailstone::new(pos[0], pos[1], pos[2], vel[0], vel[1], vel[2])
+        })
+        .collect()
+}
+
+// Where the paths of two hailstones cross in the X and Y axes, ignoring Z.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Crossing {
+    /// The paths never meet.
+    Parallel,
+    /// The paths are the same line, so they meet everywhere along it.
+    Coincident,
+    /// The paths cross where at least one of the hailstones has already been.
+    Past,
+    /// Both hailstones will pass through this point.
+    Future { x: Ratio<i128>, y: Ratio<i128> },
+}
+
+// Solves `a.pos + t * a.vel = b.pos + u * b.vel` by Cramer's rule. The
+// coordinates run to about 4e14 and the velocities to about 1e3, so the
+// products fit in i128 with room to spare, where f64 would round.
+fn crossing(a: &Hailstone, b: &Hailstone) -> Crossing {
+    let (x1, y1) = (i128::from(a.pos.x), i128::from(a.pos.y));
+    let (vx1, vy1) = (i128::from(a.vel.x), i128::from(a.vel.y));
+    let (x2, y2) = (i128::from(b.pos.x), i128::from(b.pos.y));
+    let (vx2, vy2) = (i128::from(b.vel.x), i128::from(b.vel.y));
+    let (dx, dy) = (x2 - x1, y2 - y1);
+
+    let d = vx1 * vy2 - vy1 * vx2;
+    if d == 0 {
+        // Parallel paths are the same line when the start of one lies on
+        // the other.
+        return if dx * vy1 == dy * vx1 {
+            Crossing::Coincident
+        } else {
+            Crossing::Parallel
+        };
+    }
+
+    // t = t_num / d and u = u_num / d, with a time before 0 in the past.
+    let t_num = dx * vy2 - dy * vx2;
+    let u_num = dx * vy1 - dy * vx1;
+    if t_num.signum() * d.signum() < 0 || u_num.signum() * d.signum() < 0 {
+        return Crossing::Past;
+    }
+
+    Crossing::Future {
+        x: Ratio::new(x1 * d + vx1 * t_num, d),
+        y: Ratio::new(y1 * d + vy1 * t_num, d),
+    }
+}
+
+// The rectangle the crossings are counted in, edges included.
+struct TestArea {
+    x: RangeInclusive<i64>,
+    y: RangeInclusive<i64>,
+}
+
+impl TestArea {
+    fn contains(&self, x: &Ratio<i128>, y: &Ratio<i128>) -> bool {
+        let within = |range: &RangeInclusive<i64>, value: &Ratio<i128>| {
+            Ratio::from(i128::from(*range.start())) <= *value
+                && *value <= Ratio::from(i128::from(*range.end()))
+        };
+        within(&self.x, x) && within(&self.y, y)
+    }
+}
+
+// Coincident paths cross everywhere along a line rather than at a point, and
+// no input has them, so they're counted as not crossing.
+fn solve_part_one(input: &str, area: &TestArea) -> usize {
+    parse(input)
+        .iter()
+        .tuple_combinations()
+        .filter(|&(a, b)| match crossing(a, b) {
+            Crossing::Future { x, y } => area.contains(&x, &y),
+            Crossing::Parallel | Crossing::Coincident | Crossing::Past => false,
+        })
+        .count()
+}
+
+fn solve_part_two(input: &str) -> i64 {
+    let hailstones = parse(input);
+
+    // Three hailstones with independent paths pin the rock down, and any
+    // three do in puzzle inputs, but parallel ones would leave the system
+    // singular.
+    hailstones
+        .iter()
+        .tuple_combinations()
+        .find_map(|(a, b, c)| solve(collision_equations(a, b, c)))
+        .map(|rock| rock[..3].iter().sum::<BigRational>())
+        .filter(BigRational::is_integer)
+        .and_then(|sum| sum.to_integer().to_i64())
+        .expect("no rock hits every hailstone at whole times")
+}
+
+// The six linear equations over the position and velocity of the rock, from
+// pairing `a` with `b` and with `c`, as the rows of an augmented matrix.
+fn collision_equations(a: &Hailstone, b: &Hailstone, c: &Hailstone) -> Vec<Vec<BigRational>> {
+    let mut rows = Vec::new();
+
+    for other in [b, c] {
+        // p x (v_a - v_o) + (p_a - p_o) x v = p_a x v_a - p_o x v_o
+        let d = a.vel - other.vel;
+        let e = a.pos - other.pos;
+        let rhs = cross(a.pos, a.vel)
+            .into_iter()
+            .zip(cross(other.pos, other.vel))
+            .map(|(a, o)| a - o)
+            .collect::<Vec<_>>();
+        let (d, e) = ([d.x, d.y, d.z], [e.x, e.y, e.z]);
+
+        for (i, rhs) in rhs.into_iter().enumerate() {
+            let (j, k) = ((i + 1) % 3, (i + 2) % 3);
+            // Component i of p x d is p_j d_k - p_k d_j, and of e x v is
+            // e_j v_k - e_k v_j.
+            let mut row = vec![0; 7];
+            row[j] = d[k] as i128;
+            row[k] = -d[j] as i128;
+            row[3 + k] = e[j] as i128;
+            row[3 + j] = -e[k] as i128;
+            row[6] = rhs;
+            rows.push(
+                row.into_iter()
+                    .map(|n| BigRational::from_integer(BigInt::from(n)))
+                    .collect(),
+            );
+        }
+    }
+
+    rows
+}
+
+fn cross(a: Point3, b: Point3) -> [i128; 3] {
+    let (a, b) = (
+        [a.x, a.y, a.z].map(i128::from),
+        [b.x, b.y, b.z].map(i128::from),
+    );
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+// Gauss-Jordan elimination of an augmented matrix, `None` if it's singular.
+fn solve(mut rows: Vec<Vec<BigRational>>) -> Option<Vec<BigRational>> {
+    let n = rows.len();
+
+    for col in 0..n {
+        let pivot = (col..n).max_by_key(|&row| rows[row][col].abs())?;
+        if rows[pivot][col].is_zero() {
+            return None;
+        }
+        rows.swap(col, pivot);
+
+        let scale = rows[col][col].clone();
+        let pivot: Vec<BigRational> = rows[col].iter().map(|value| value / &scale).collect();
+        for (i, row) in rows.iter_mut().enumerate() {
+            let factor = row[col].clone();
+            if i == col || factor.is_zero() {
+                continue;
+            }
+            for (value, p) in row[col..].iter_mut().zip(&pivot[col..]) {
+                *value -= &factor * p;
+            }
+        }
+        rows[col] = pivot;
+    }
+
+    Some(rows.into_iter().map(|row| row[n].clone()).collect())
+}
+
+// Only used to cross-check `solve_part_two` in the tests.
+#[cfg(all(test, feature = "z3"))]
+fn solve_part_two_z3(input: &str) -> i64 {
+    use z3::ast::{Ast, Int};
+
+    let hailstones = parse(input);
+
+    let ctx = z3::Context::new(&z3::Config::default());
+    let solver = z3::Solver::new(&ctx);
+
+    let (x, y, z, vx, vy, vz) = (
+        Int::new_const(&ctx, "x"),
+        Int::new_const(&ctx, "y"),
+        Int::new_const(&ctx, "z"),
+        Int::new_const(&ctx, "vx"),
+        Int::new_const(&ctx, "vy"),
+        Int::new_const(&ctx, "vz"),
+    );
+
+    for hailstone in hailstones {
+        let (pxn, pyn, pzn) = (
+            Int::from_i64(&ctx, hailstone.pos.x),
+            Int::from_i64(&ctx, hailstone.pos.y),
+            Int::from_i64(&ctx, hailstone.pos.z),
+        );
+
+        let (vxn, vyn, vzn) = (
+            Int::from_i64(&ctx, hailstone.vel.x),
+            Int::from_i64(&ctx, hailstone.vel.y),
+            Int::from_i64(&ctx, hailstone.vel.z),
+        );
+
+        let tn = Int::fresh_const(&ctx, "t");
+
+        solver.assert(&(&pxn + &vxn * &tn)._eq(&(&x + &vx * &tn)));
+        solver.assert(&(&pyn + &vyn * &tn)._eq(&(&y + &vy * &tn)));
+        solver.assert(&(&pzn + &vzn * &tn)._eq(&(&z + &vz * &tn)));
+    }
+
+    assert_eq!(solver.check(), z3::SatResult::Sat);
+    let model = solver.get_model().unwrap();
+    let out = model.eval(&(x + y + z), true).unwrap();
+    out.as_i64().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use num::One;
+
+    use super::*;
+    use crate::assert_known_answers;
+
+    #[test]
+    fn known_answers() {
+        assert_known_answers::<Day24>();
+    }
+
+    #[test]
+    fn test_crossing() {
+        let hailstones = parse(&crate::YEAR.read_example("24_one.txt").unwrap());
+        let [a, b, c, d, e] = hailstones[..] else {
+            panic!("expected five hailstones");
+        };
+
+        assert_eq!(
+            crossing(&a, &b),
+            Crossing::Future {
+                x: Ratio::new(43, 3),
+                y: Ratio::new(46, 3)
+            }
+        );
+        assert_eq!(crossing(&a, &e), Crossing::Past);
+        assert_eq!(crossing(&b, &c), Crossing::Parallel);
+        assert_eq!(crossing(&d, &e), Crossing::Past);
+
+        let same_line = Hailstone::new(16, 17, 0, -1, -1, 0);
+        assert_eq!(crossing(&b, &same_line), Crossing::Coincident);
+    }
+
+    #[test]
+    fn test_test_area() {
+        let area = TestArea {
+            x: 7..=27,
+            y: 0..=10,
+        };
+
+        assert!(area.contains(&Ratio::from(7), &Ratio::from(10)));
+        assert!(area.contains(&Ratio::new(81, 3), &Ratio::new(1, 2)));
+        assert!(!area.contains(&Ratio::new(82, 3), &Ratio::from(5)));
+        assert!(!area.contains(&Ratio::from(20), &Ratio::new(-1, 1000)));
+    }
+
+    #[test]
+    fn test_solve() {
+        let rows = [[2, 1, 5], [4, -6, -2]]
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|&n| BigRational::from_integer(n.into()))
+                    .collect()
+            })
+            .collect();
+        let solution = solve(rows).unwrap();
+        assert_eq!(solution[0], BigRational::new(7.into(), 4.into()));
+        assert_eq!(solution[1], BigRational::new(3.into(), 2.into()));
+
+        let parallel = vec![vec![BigRational::one(); 3]; 2];
+        assert_eq!(solve(parallel), None);
+    }
+
+    #[cfg(feature = "z3")]
+    #[test]
+    fn z3_agrees() {
+        let mut inputs = vec![crate::YEAR.read_example("24_one.txt").unwrap()];
+        inputs.extend(crate::YEAR.read_input("24.txt").ok());
+
+        for input in inputs {
+            assert_eq!(solve_part_two(&input), solve_part_two_z3(&input));
+        }
+    }
+}
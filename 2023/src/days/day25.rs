@@ -0,0 +1,56 @@
+use crate::{graph::Graph, Answer, Solution};
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Answer {
+        solve(input).into()
+    }
+
+    fn part_two(_input: &Self::Input<'_>) -> Answer {
+        "Merry Christmas!".into()
+    }
+}
+
+// The wires to cut are the minimum cut of the graph, which splits it into the
+// two groups. `graph.to_dot(&cut.edges)` draws it with the wires to cut in
+// red.
+fn solve(input: &str) -> usize {
+    let graph = parse(input);
+    let cut = graph.min_cut().unwrap();
+    assert_eq!(cut.edges.len(), 3, "expected to cut three wires");
+
+    let (a, b) = cut.partition;
+    a.len() * b.len()
+}
+
+fn parse(input: &str) -> Graph<'_> {
+    input
+        .lines()
+        .flat_map(|line| {
+            let (component, others) = line.split_once(": ").unwrap();
+            others
+                .split_whitespace()
+                .map(move |other| (component, other))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_known_answers;
+
+    #[test]
+    fn known_answers() {
+        assert_known_answers::<Day25>();
+    }
+}
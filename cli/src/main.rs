@@ -0,0 +1,580 @@
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+    process::{self, Command as Process},
+    time::Duration,
+};
+
+use aoc_core::{
+    answers::{Answers, Verdict},
+    isolation,
+    runner::{self, Outcome, Report},
+    vault::{self, Key},
+    AocError, InputSource, Part, Year,
+};
+use clap::{Args, Parser, Subcommand};
+
+mod scaffold;
+
+/// Every year with solutions, in order.
+const YEARS: &[Year] = &[aoc_2022::YEAR, aoc_2023::YEAR];
+
+/// Advent of Code solutions for every year.
+#[derive(Parser)]
+#[command(name = "aoc")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a year, a day or a single part against the puzzle inputs
+    Run {
+        #[command(flatten)]
+        select: Select,
+        /// Only solve this part of the day
+        #[arg(short, long, requires = "day", value_parser = parse_part)]
+        part: Option<Part>,
+        /// Read the input from this file, or from stdin with `-`
+        #[arg(short, long, requires = "day")]
+        input: Option<String>,
+        /// Give up on a part after this many seconds, 0 to wait forever
+        #[arg(short, long, default_value_t = 60)]
+        timeout: u64,
+        #[command(flatten)]
+        output: Output,
+    },
+    /// Run the example tests, without the puzzle inputs
+    Test {
+        #[command(flatten)]
+        select: Select,
+    },
+    /// Time days over several runs, keeping the fastest run of each step
+    Bench {
+        #[command(flatten)]
+        select: Select,
+        /// How many times to solve each day
+        #[arg(short, long, default_value_t = 10)]
+        runs: usize,
+        /// Give up on a part after this many seconds, 0 to wait forever
+        #[arg(short, long, default_value_t = 60)]
+        timeout: u64,
+        #[command(flatten)]
+        output: Output,
+    },
+    /// Create a day from the template of its year and register it
+    New {
+        #[arg(short, long, value_parser = parse_year)]
+        year: u16,
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Show which days of each year are solved
+    List {
+        /// Only list this year
+        #[arg(short, long, value_parser = parse_year)]
+        year: Option<u16>,
+    },
+    /// Encrypt the puzzle inputs so they can be committed, or decrypt them
+    Inputs {
+        #[command(subcommand)]
+        action: InputsAction,
+    },
+}
+
+#[derive(Subcommand)]
+enum InputsAction {
+    /// Write an encrypted copy next to every input, creating a key if there
+    /// is none
+    Encrypt {
+        /// Only encrypt the inputs of this year
+        #[arg(short, long, value_parser = parse_year)]
+        year: Option<u16>,
+    },
+    /// Write the plain text of every encrypted input
+    Decrypt {
+        /// Only decrypt the inputs of this year
+        #[arg(short, long, value_parser = parse_year)]
+        year: Option<u16>,
+    },
+}
+
+#[derive(Args)]
+struct Select {
+    /// Only this year, all years by default
+    #[arg(short, long, value_parser = parse_year)]
+    year: Option<u16>,
+    /// Only this day of the year
+    #[arg(short, long, requires = "year", value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+}
+
+impl Select {
+    /// The selected years, each with the selected days.
+    fn days(&self) -> Vec<(&'static Year, Vec<u8>)> {
+        YEARS
+            .iter()
+            .filter(|year| self.year.is_none_or(|y| y == year.number))
+            .map(|year| (year, self.day.map_or((1..=25).collect(), |d| vec![d])))
+            .collect()
+    }
+}
+
+#[derive(Args)]
+struct Output {
+    /// Also write the answers and timings to this JSON file
+    #[arg(long)]
+    json: Option<PathBuf>,
+    /// Also write the answers and timings to this CSV file
+    #[arg(long)]
+    csv: Option<PathBuf>,
+}
+
+impl Output {
+    fn write(&self, reports: &[(u16, Report)]) -> Result<(), AocError> {
+        if let Some(path) = &self.json {
+            write(path, runner::to_json(reports))?;
+        }
+        if let Some(path) = &self.csv {
+            write(path, runner::to_csv(reports))?;
+        }
+
+        Ok(())
+    }
+}
+
+fn write(path: &Path, contents: impl AsRef<[u8]>) -> Result<(), AocError> {
+    fs::write(path, contents).map_err(|source| AocError::Write {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+fn parse_year(year: &str) -> Result<u16, String> {
+    year.parse()
+        .ok()
+        .filter(|year| YEARS.iter().any(|y| y.number == *year))
+        .ok_or_else(|| format!("no solutions for year {}", year))
+}
+
+fn parse_part(part: &str) -> Result<Part, String> {
+    match part {
+        "1" | "one" => Ok(Part::One),
+        "2" | "two" => Ok(Part::Two),
+        _ => Err(format!("{} is not a part, expected 1 or 2", part)),
+    }
+}
+
+fn main() {
+    let result = match Cli::parse().command {
+        Command::Run {
+            select,
+            part,
+            input,
+            timeout,
+            output,
+        } => {
+            let timeout = (timeout > 0).then(|| Duration::from_secs(timeout));
+            run(&select, part, input.as_deref(), timeout, &output)
+        }
+        Command::Test { select } => test(&select),
+        Command::Bench {
+            select,
+            runs,
+            timeout,
+            output,
+        } => {
+            let timeout = (timeout > 0).then(|| Duration::from_secs(timeout));
+            bench(&select, runs, timeout, &output)
+        }
+        Command::New { year, day } => new(year, day),
+        Command::List { year } => list(year),
+        Command::Inputs {
+            action: InputsAction::Encrypt { year },
+        } => encrypt(year),
+        Command::Inputs {
+            action: InputsAction::Decrypt { year },
+        } => decrypt(year),
+    };
+
+    match result {
+        Ok(true) => {}
+        Ok(false) => process::exit(1),
+        Err(err) => {
+            eprintln!("{}", err);
+            process::exit(1);
+        }
+    }
+}
+
+fn banner(year: &Year, day: u8) {
+    println!("---------------");
+    println!("| {} Day {:02} |", year.number, day);
+    println!("---------------");
+}
+
+// Returns whether every part ran to an answer and every answer with a known
+// value was right.
+fn run(
+    select: &Select,
+    part: Option<Part>,
+    input: Option<&str>,
+    timeout: Option<Duration>,
+    output: &Output,
+) -> Result<bool, AocError> {
+    let source = input.map_or(InputSource::Default, InputSource::from_arg);
+    let mut reports = Vec::new();
+    let (mut correct, mut wrong, mut unknown, mut failed) = (0, 0, 0, 0);
+
+    for (year, days) in select.days() {
+        // Known answers only apply to the inputs they were recorded for.
+        let answers = match source {
+            InputSource::Default => year.answers()?,
+            _ => Answers::default(),
+        };
+
+        for day in days {
+            banner(year, day);
+
+            let Some(d) = year.day(day) else {
+                println!("Not solved.");
+                continue;
+            };
+
+            match source.read(year, day) {
+                Ok(input) => {
+                    let report = isolation::solve_isolated(d, &input, part, timeout);
+
+                    for (part, outcome) in report.parts() {
+                        let Outcome::Solved(timed) = outcome else {
+                            failed += 1;
+                            println!("{}", outcome);
+                            continue;
+                        };
+                        let verdict = answers.check(day, part, &timed.value);
+                        match verdict {
+                            Verdict::Correct => correct += 1,
+                            Verdict::Wrong(_) => wrong += 1,
+                            Verdict::Unknown => unknown += 1,
+                        }
+                        println!("{} ({})", timed.value, verdict);
+                    }
+                    reports.push((year.number, report));
+                }
+                Err(err @ AocError::MissingInput { .. }) => {
+                    println!("Input missing.");
+                    eprintln!("{}", err);
+                    reports.push((year.number, Report::missing_input(day, part)));
+                }
+                // Such as a file given with `--input` that doesn't exist, or
+                // an encrypted input without its key, which fail the parts
+                // unlike a default input that was never downloaded.
+                Err(err) => {
+                    println!("Input unreadable.");
+                    eprintln!("{}", err);
+                    failed += part.map_or(Part::ALL.len(), |_| 1);
+                    reports.push((year.number, Report::unreadable_input(day, part, &err)));
+                }
+            }
+        }
+    }
+
+    println!();
+    print!("{}", runner::summary(&reports));
+    println!();
+    println!(
+        "Answers: {} correct, {} wrong, {} unknown, {} failed",
+        correct, wrong, unknown, failed
+    );
+    output.write(&reports)?;
+
+    Ok(wrong == 0 && failed == 0)
+}
+
+// The example tests are generated from the examples manifest of each year,
+// so this runs them through cargo and leaves out the `known_answers` test of
+// each day, which needs the inputs.
+fn test(select: &Select) -> Result<bool, AocError> {
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let mut command = Process::new(&cargo);
+
+    command
+        .current_dir(concat!(env!("CARGO_MANIFEST_DIR"), "/.."))
+        .arg("test");
+    for (year, _) in select.days() {
+        command.args(["-p", &format!("aoc-{}", year.number)]);
+    }
+    if let Some(day) = select.day {
+        command.arg(format!("day{:02}::", day));
+    }
+    command.args(["--", "--skip", "known_answers"]);
+
+    let status = command.status().map_err(|source| AocError::Command {
+        program: cargo.to_string_lossy().into_owned(),
+        source,
+    })?;
+
+    Ok(status.success())
+}
+
+// Returns whether every part of every day with an input ran to an answer.
+fn bench(
+    select: &Select,
+    runs: usize,
+    timeout: Option<Duration>,
+    output: &Output,
+) -> Result<bool, AocError> {
+    let mut reports = Vec::new();
+    let mut failed = 0;
+
+    for (year, days) in select.days() {
+        for day in days {
+            let Some(d) = year.day(day) else { continue };
+
+            match year.read_input(&format!("{:02}.txt", day)) {
+                Ok(input) => {
+                    println!("{} day {:02}: {} runs", year.number, day, runs);
+                    let report = runner::bench(runs.max(1), || {
+                        isolation::solve_isolated(d, &input, None, timeout)
+                    });
+
+                    for (part, outcome) in report.parts() {
+                        if outcome.answer().is_none() {
+                            failed += 1;
+                            println!("{}: {}", part, outcome);
+                        }
+                    }
+                    reports.push((year.number, report));
+                }
+                Err(err @ AocError::MissingInput { .. }) => eprintln!("{}", err),
+                Err(err) => {
+                    eprintln!("{}", err);
+                    failed += Part::ALL.len();
+                    reports.push((year.number, Report::unreadable_input(day, None, &err)));
+                }
+            }
+        }
+    }
+
+    println!();
+    print!("{}", runner::summary(&reports));
+    println!();
+    println!("{} failed", failed);
+    output.write(&reports)?;
+
+    Ok(failed == 0)
+}
+
+fn new(year: u16, day: u8) -> Result<bool, AocError> {
+    let year = YEARS.iter().find(|y| y.number == year).unwrap();
+    let scaffold = scaffold::new_day(Path::new(year.dir), day)?;
+
+    for path in &scaffold.created {
+        println!("created {}", path.display());
+    }
+    if let Some(path) = &scaffold.kept_input {
+        println!("kept {}", path.display());
+    }
+
+    Ok(true)
+}
+
+fn list(year: Option<u16>) -> Result<bool, AocError> {
+    for year in years(year) {
+        let answers = year.answers()?;
+
+        println!("{}", year.number);
+        println!("{:>5}  {:<9} {:<9} Known", "Day", "Status", "Input");
+        for day in 1..=25 {
+            let status = if year.day(day).is_some() {
+                "solved"
+            } else {
+                "unsolved"
+            };
+            let input = if year.input_path(day).exists() {
+                "present"
+            } else if vault::encrypted_path(&year.input_path(day)).exists() {
+                "encrypted"
+            } else {
+                "missing"
+            };
+            let known = Part::ALL
+                .iter()
+                .filter(|part| answers.get(day, **part).is_some())
+                .count();
+
+            println!(
+                "{:>5}  {:<9} {:<9} {}/2",
+                format!("{:02}", day),
+                status,
+                input,
+                known
+            );
+        }
+        println!();
+    }
+
+    Ok(true)
+}
+
+fn years(year: Option<u16>) -> impl Iterator<Item = &'static Year> {
+    YEARS
+        .iter()
+        .filter(move |y| year.is_none_or(|n| n == y.number))
+}
+
+fn read(path: &Path) -> Result<Option<Vec<u8>>, AocError> {
+    match fs::read(path) {
+        Ok(contents) => Ok(Some(contents)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(source) => Err(AocError::Io {
+            path: path.to_path_buf(),
+            source,
+        }),
+    }
+}
+
+fn input_name(path: &Path) -> String {
+    path.file_name().unwrap().to_string_lossy().into_owned()
+}
+
+// Inputs already encrypted with the same plain text are left alone, so
+// encrypting again doesn't change every file with a new nonce.
+fn encrypt(year: Option<u16>) -> Result<bool, AocError> {
+    let key = match Key::load()? {
+        Some(key) => key,
+        None => {
+            let path = Key::default_path().ok_or_else(|| {
+                AocError::Usage(format!(
+                    "there is no home directory for a new key, set {} instead",
+                    vault::KEY_VAR
+                ))
+            })?;
+            let key = Key::generate();
+            key.save(&path)?;
+            println!("created a key in {}, keep a copy of it", path.display());
+            key
+        }
+    };
+
+    for year in years(year) {
+        for day in 1..=25 {
+            let path = year.input_path(day);
+            let Some(plaintext) = read(&path)? else {
+                continue;
+            };
+            let encrypted = vault::encrypted_path(&path);
+            let name = input_name(&path);
+
+            if let Some(data) = read(&encrypted)? {
+                if vault::decrypt(&key, &name, &data, &encrypted).ok() == Some(plaintext.clone()) {
+                    continue;
+                }
+            }
+            write(&encrypted, vault::encrypt(&key, &name, &plaintext))?;
+            println!("encrypted {}", encrypted.display());
+        }
+    }
+
+    Ok(true)
+}
+
+// Refuses to replace an input whose plain text differs from its encrypted
+// copy, since that input may be the newer of the two.
+fn decrypt(year: Option<u16>) -> Result<bool, AocError> {
+    let key = Key::load()?.ok_or_else(|| {
+        AocError::Usage(format!(
+            "there is no key, set {} or put it in ~/.config/aoc/inputs.key",
+            vault::KEY_VAR
+        ))
+    })?;
+
+    for year in years(year) {
+        for day in 1..=25 {
+            let path = year.input_path(day);
+            let encrypted = vault::encrypted_path(&path);
+            let Some(data) = read(&encrypted)? else {
+                continue;
+            };
+            let plaintext = vault::decrypt(&key, &input_name(&path), &data, &encrypted)?;
+
+            match read(&path)? {
+                Some(existing) if existing == plaintext => continue,
+                Some(_) => return Err(AocError::AlreadyExists(path)),
+                None => {}
+            }
+            write(&path, plaintext)?;
+            println!("decrypted {}", path.display());
+        }
+    }
+
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    #[test]
+    fn test_cli() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn test_run_args() {
+        let cli = Cli::try_parse_from([
+            "aoc", "run", "-y", "2023", "-d", "7", "-p", "2", "-i", "-", "-t", "5",
+        ]);
+        let Ok(Cli {
+            command:
+                Command::Run {
+                    select,
+                    part,
+                    input,
+                    timeout,
+                    ..
+                },
+        }) = cli
+        else {
+            panic!("expected a run command");
+        };
+
+        assert_eq!((select.year, select.day), (Some(2023), Some(7)));
+        assert_eq!(part, Some(Part::Two));
+        assert_eq!(input.as_deref(), Some("-"));
+        assert_eq!(timeout, 5);
+        assert_eq!(select.days().len(), 1);
+    }
+
+    #[test]
+    fn test_invalid_args() {
+        for args in [
+            &["aoc", "run", "--year", "1999"][..],
+            &["aoc", "run", "--year", "2023", "--day", "26"],
+            &["aoc", "run", "--day", "1"],
+            &["aoc", "run", "--year", "2023", "--part", "1"],
+            &["aoc", "run", "--year", "2023", "--day", "1", "--part", "3"],
+            &["aoc", "run", "--year", "2023", "--input", "a.txt"],
+        ] {
+            assert!(Cli::try_parse_from(args).is_err(), "{:?}", args);
+        }
+    }
+
+    #[test]
+    fn test_inputs_args() {
+        let cli = Cli::try_parse_from(["aoc", "inputs", "encrypt", "-y", "2023"]);
+        assert!(matches!(
+            cli,
+            Ok(Cli {
+                command: Command::Inputs {
+                    action: InputsAction::Encrypt { year: Some(2023) }
+                }
+            })
+        ));
+
+        assert!(Cli::try_parse_from(["aoc", "inputs"]).is_err());
+        assert!(Cli::try_parse_from(["aoc", "inputs", "decrypt", "-d", "1"]).is_err());
+    }
+}
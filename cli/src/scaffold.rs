@@ -0,0 +1,338 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use aoc_core::{examples::EXAMPLES_FILE, AocError};
+
+/// The files `new_day` created, and an input it kept because it was already
+/// downloaded.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Scaffold {
+    pub created: Vec<PathBuf>,
+    pub kept_input: Option<PathBuf>,
+}
+
+/// Creates a day in the year crate at `year_dir` from its `day00.rs` template:
+/// the solution module, an empty input, two empty example inputs, the entries
+/// in `days/mod.rs` that register it, and a copy of the template's examples
+/// in `examples.toml`.
+///
+/// Nothing is written if the module, the examples or the registration already
+/// exist. An existing input is kept as it is.
+pub fn new_day(year_dir: &Path, day: u8) -> Result<Scaffold, AocError> {
+    let days_dir = year_dir.join("src/days");
+    let module = days_dir.join(format!("day{:02}.rs", day));
+    let registry = days_dir.join("mod.rs");
+    let input = year_dir.join(format!("src/inputs/{:02}.txt", day));
+    let manifest = year_dir.join("src/test_inputs").join(EXAMPLES_FILE);
+    let examples = ["one", "two"]
+        .map(|part| year_dir.join(format!("src/test_inputs/{:02}_{}.txt", day, part)));
+
+    for path in [&module].into_iter().chain(&examples) {
+        if path.exists() {
+            return Err(AocError::AlreadyExists(path.clone()));
+        }
+    }
+
+    let template = read(&days_dir.join("day00.rs"))?;
+    let source = fill_template(&template, day).ok_or_else(|| {
+        AocError::Usage(format!(
+            "{} is not a template, expected `const DAY: u8 = 0;`",
+            days_dir.join("day00.rs").display()
+        ))
+    })?;
+    let registered = register(&read(&registry)?, day).ok_or_else(|| {
+        AocError::Usage(format!(
+            "day {:02} is already registered in {}",
+            day,
+            registry.display()
+        ))
+    })?;
+
+    let listed = add_examples(&read(&manifest)?, day).ok_or_else(|| {
+        AocError::Usage(format!(
+            "day {:02} already has examples in {}",
+            day,
+            manifest.display()
+        ))
+    })?;
+
+    let mut scaffold = Scaffold::default();
+    write(&module, &source)?;
+    scaffold.created.push(module);
+    for example in examples {
+        write(&example, "")?;
+        scaffold.created.push(example);
+    }
+    if input.exists() {
+        scaffold.kept_input = Some(input);
+    } else {
+        write(&input, "")?;
+        scaffold.created.push(input);
+    }
+    write(&registry, &registered)?;
+    write(&manifest, &listed)?;
+
+    Ok(scaffold)
+}
+
+fn read(path: &Path) -> Result<String, AocError> {
+    fs::read_to_string(path).map_err(|source| AocError::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), AocError> {
+    fs::write(path, contents).map_err(|source| AocError::Write {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+// Swaps day 0 for the new day in the type name, the `DAY` constant and the
+// example file names of the template.
+fn fill_template(template: &str, day: u8) -> Option<String> {
+    let constant = "const DAY: u8 = 0;";
+    if !template.contains(constant) {
+        return None;
+    }
+
+    Some(
+        template
+            .replace(constant, &format!("const DAY: u8 = {};", day))
+            .replace("Day00", &format!("Day{:02}", day))
+            .replace("\"00_", &format!("\"{:02}_", day)),
+    )
+}
+
+// Adds `pub mod dayNN;` and the `DAYS` entry of a day in order, or returns
+// `None` if the day is already declared.
+fn register(registry: &str, day: u8) -> Option<String> {
+    let module = format!("pub mod day{:02};", day);
+    let entry = format!("    Day::new::<day{:02}::Day{:02}>(),", day, day);
+    if registry.lines().any(|line| line == module || line == entry) {
+        return None;
+    }
+
+    let mut lines: Vec<String> = registry.lines().map(String::from).collect();
+    insert_sorted(&mut lines, module, |line| line.starts_with("pub mod day"));
+    insert_sorted(&mut lines, entry, |line| {
+        line.starts_with("    Day::new::<day")
+    });
+
+    Some(lines.join("\n") + "\n")
+}
+
+// Copies the examples of day 00 in the examples manifest to the new day,
+// before the first day after it, or returns `None` if the day already has
+// examples.
+fn add_examples(manifest: &str, day: u8) -> Option<String> {
+    let header = |line: &str| {
+        line.strip_prefix("[[")
+            .and_then(|line| line.strip_suffix("]]"))
+            .and_then(|day| day.parse::<u8>().ok())
+    };
+    if manifest.lines().any(|line| header(line) == Some(day)) {
+        return None;
+    }
+
+    // The tables of the manifest, each with the comments and blank lines
+    // that follow it, after whatever comes before the first one.
+    let mut tables: Vec<(Option<u8>, Vec<&str>)> = vec![(None, Vec::new())];
+    for line in manifest.lines() {
+        match header(line) {
+            Some(n) => tables.push((Some(n), vec![line])),
+            None => tables.last_mut().unwrap().1.push(line),
+        }
+    }
+
+    let mut copies: Vec<String> = tables
+        .iter()
+        .filter(|(n, _)| *n == Some(0))
+        .flat_map(|(_, lines)| lines)
+        .map(|line| {
+            line.replace("[[00]]", &format!("[[{:02}]]", day))
+                .replace("\"00_", &format!("\"{:02}_", day))
+        })
+        .collect();
+    if copies.last().is_some_and(|line| !line.is_empty()) {
+        copies.push(String::new());
+    }
+    let at = tables
+        .iter()
+        .position(|(n, _)| n.is_some_and(|n| n > day))
+        .unwrap_or(tables.len());
+
+    let mut lines: Vec<String> = Vec::new();
+    for (i, (_, table)) in tables.iter().enumerate() {
+        if i == at {
+            lines.extend(copies.iter().cloned());
+        }
+        lines.extend(table.iter().map(|line| line.to_string()));
+    }
+    if at == tables.len() {
+        if lines.last().is_some_and(|line| !line.is_empty()) {
+            lines.push(String::new());
+        }
+        lines.extend(copies);
+    }
+
+    Some(lines.join("\n").trim_end().to_string() + "\n")
+}
+
+// Inserts `line` among the lines matching `is_peer`, keeping them sorted. The
+// day numbers are zero padded, so string order is day order.
+fn insert_sorted(lines: &mut Vec<String>, line: String, is_peer: impl Fn(&str) -> bool) {
+    let peers: Vec<usize> = (0..lines.len()).filter(|&i| is_peer(&lines[i])).collect();
+    let at = peers
+        .iter()
+        .copied()
+        .find(|&i| lines[i] > line)
+        .unwrap_or_else(|| peers.last().map_or(lines.len(), |last| last + 1));
+
+    lines.insert(at, line);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REGISTRY: &str = "use aoc_core::Day;
+
+pub mod day00;
+pub mod day01;
+pub mod day03;
+
+/// Every solved day of the year, in order.
+pub const DAYS: &[Day] = &[
+    Day::new::<day01::Day01>(),
+    Day::new::<day03::Day03>(),
+];
+";
+
+    #[test]
+    fn test_register() {
+        let registry = register(REGISTRY, 2).unwrap();
+
+        assert!(registry.contains("pub mod day01;\npub mod day02;\npub mod day03;\n"));
+        assert!(registry.contains(
+            "    Day::new::<day01::Day01>(),\n    Day::new::<day02::Day02>(),\n    Day::new::<day03::Day03>(),\n];"
+        ));
+
+        let registry = register(REGISTRY, 25).unwrap();
+        assert!(registry.contains("pub mod day03;\npub mod day25;\n\n"));
+        assert!(registry.contains("    Day::new::<day25::Day25>(),\n];"));
+
+        assert_eq!(register(REGISTRY, 3), None);
+    }
+
+    const EXAMPLES: &str = "# Examples.
+
+[[00]]
+file = \"00_one.txt\"
+part = 1
+expected = 0
+
+[[00]]
+file = \"00_two.txt\"
+part = 2
+expected = 1
+
+[[01]]
+file = \"01_one.txt\"
+part = 1
+expected = 142
+
+[[03]]
+file = \"03_one.txt\"
+part = 1
+expected = 4361
+";
+
+    #[test]
+    fn test_add_examples() {
+        let copies = "[[02]]
+file = \"02_one.txt\"
+part = 1
+expected = 0
+
+[[02]]
+file = \"02_two.txt\"
+part = 2
+expected = 1
+";
+
+        let examples = add_examples(EXAMPLES, 2).unwrap();
+        assert!(examples.contains(&format!("expected = 142\n\n{}\n[[03]]", copies)));
+
+        let examples = add_examples(EXAMPLES, 25).unwrap();
+        assert!(examples.ends_with(&format!(
+            "expected = 4361\n\n{}",
+            copies.replace("02", "25")
+        )));
+
+        assert_eq!(add_examples(EXAMPLES, 3), None);
+    }
+
+    #[test]
+    fn test_fill_template() {
+        let template = "pub struct Day00;\n\
+                        impl Solution for Day00 {\n    const DAY: u8 = 0;\n}\n\
+                        read_test_file_input(\"00_one.txt\".to_string())";
+
+        assert_eq!(
+            fill_template(template, 7).unwrap(),
+            "pub struct Day07;\n\
+             impl Solution for Day07 {\n    const DAY: u8 = 7;\n}\n\
+             read_test_file_input(\"07_one.txt\".to_string())"
+        );
+        assert_eq!(fill_template("pub struct Day00;", 7), None);
+    }
+
+    #[test]
+    fn test_new_day() {
+        let dir = tempfile::tempdir().unwrap();
+        let year_dir = dir.path();
+        for sub in ["src/days", "src/inputs", "src/test_inputs"] {
+            fs::create_dir_all(year_dir.join(sub)).unwrap();
+        }
+        fs::write(year_dir.join("src/days/mod.rs"), REGISTRY).unwrap();
+        fs::write(
+            year_dir.join("src/days/day00.rs"),
+            "pub struct Day00;\nconst DAY: u8 = 0;\n",
+        )
+        .unwrap();
+        fs::write(year_dir.join("src/inputs/02.txt"), "downloaded").unwrap();
+        fs::write(year_dir.join("src/test_inputs/examples.toml"), EXAMPLES).unwrap();
+
+        let scaffold = new_day(year_dir, 2).unwrap();
+        assert_eq!(scaffold.created.len(), 3);
+        assert_eq!(
+            scaffold.kept_input,
+            Some(year_dir.join("src/inputs/02.txt"))
+        );
+        assert_eq!(
+            fs::read_to_string(year_dir.join("src/days/day02.rs")).unwrap(),
+            "pub struct Day02;\nconst DAY: u8 = 2;\n"
+        );
+        assert_eq!(
+            fs::read_to_string(year_dir.join("src/inputs/02.txt")).unwrap(),
+            "downloaded"
+        );
+        assert!(year_dir.join("src/test_inputs/02_two.txt").exists());
+        assert!(
+            fs::read_to_string(year_dir.join("src/test_inputs/examples.toml"))
+                .unwrap()
+                .contains("[[02]]\nfile = \"02_one.txt\"")
+        );
+
+        assert!(matches!(
+            new_day(year_dir, 2),
+            Err(AocError::AlreadyExists(_))
+        ));
+        assert!(matches!(new_day(year_dir, 3), Err(AocError::Usage(_))));
+        assert!(!year_dir.join("src/days/day03.rs").exists());
+    }
+}
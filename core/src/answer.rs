@@ -0,0 +1,210 @@
+use std::{convert::Infallible, fmt, str::FromStr};
+
+use serde::{
+    de::{self, Visitor},
+    Deserialize, Deserializer,
+};
+
+/// The answer to one part of a puzzle.
+///
+/// Two answers are equal when they display the same, so numeric answers
+/// compare by value regardless of the variant they are stored in:
+/// `Answer::from(42u32)` equals `Answer::from(42i64)` and `Answer::from("42")`.
+#[derive(Debug, Clone)]
+pub enum Answer {
+    Signed(i64),
+    Unsigned(u64),
+    BigSigned(i128),
+    BigUnsigned(u128),
+    Text(String),
+}
+
+impl Answer {
+    // The sign and magnitude of a numeric answer, or `None` for text.
+    fn as_number(&self) -> Option<(bool, u128)> {
+        match *self {
+            Answer::Signed(n) => Some((n < 0, n.unsigned_abs() as u128)),
+            Answer::Unsigned(n) => Some((false, n as u128)),
+            Answer::BigSigned(n) => Some((n < 0, n.unsigned_abs())),
+            Answer::BigUnsigned(n) => Some((false, n)),
+            Answer::Text(_) => None,
+        }
+    }
+}
+
+// The sign and magnitude of an integer written out in full.
+fn parse_number(s: &str) -> Option<(bool, u128)> {
+    match s.parse::<u128>() {
+        Ok(n) => Some((false, n)),
+        Err(_) => s.parse::<i128>().ok().map(|n| (n < 0, n.unsigned_abs())),
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Signed(n) => write!(f, "{}", n),
+            Answer::Unsigned(n) => write!(f, "{}", n),
+            Answer::BigSigned(n) => write!(f, "{}", n),
+            Answer::BigUnsigned(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.as_number(), other.as_number()) {
+            (Some(n1), Some(n2)) => n1 == n2,
+            _ => self.to_string() == other.to_string(),
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    // Anything that isn't an integer is kept as a text answer.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        Ok(if let Ok(n) = s.parse::<u64>() {
+            Answer::Unsigned(n)
+        } else if let Ok(n) = s.parse::<i64>() {
+            Answer::Signed(n)
+        } else if let Ok(n) = s.parse::<u128>() {
+            Answer::BigUnsigned(n)
+        } else if let Ok(n) = s.parse::<i128>() {
+            Answer::BigSigned(n)
+        } else {
+            Answer::Text(s.to_string())
+        })
+    }
+}
+
+// Answers in the answers manifest are written as integers, or as strings when
+// they are text or don't fit in a TOML integer.
+impl<'de> Deserialize<'de> for Answer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct AnswerVisitor;
+
+        impl Visitor<'_> for AnswerVisitor {
+            type Value = Answer;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "an integer or a string")
+            }
+
+            fn visit_i64<E: de::Error>(self, n: i64) -> Result<Answer, E> {
+                Ok(n.into())
+            }
+
+            fn visit_u64<E: de::Error>(self, n: u64) -> Result<Answer, E> {
+                Ok(n.into())
+            }
+
+            fn visit_str<E: de::Error>(self, s: &str) -> Result<Answer, E> {
+                s.parse().map_err(|never| match never {})
+            }
+        }
+
+        deserializer.deserialize_any(AnswerVisitor)
+    }
+}
+
+macro_rules! impl_from {
+    ($variant:ident: $($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::$variant(n as _)
+                }
+            }
+
+            impl PartialEq<$t> for Answer {
+                fn eq(&self, other: &$t) -> bool {
+                    let n = *other as i128;
+                    self.as_number() == Some((n < 0, n.unsigned_abs()))
+                }
+            }
+        )*
+    };
+}
+
+impl_from!(Signed: i8, i16, i32, i64, isize);
+impl_from!(Unsigned: u8, u16, u32, u64, usize);
+impl_from!(BigSigned: i128);
+
+impl From<u128> for Answer {
+    fn from(n: u128) -> Self {
+        Answer::BigUnsigned(n)
+    }
+}
+
+impl PartialEq<u128> for Answer {
+    fn eq(&self, other: &u128) -> bool {
+        self.as_number() == Some((false, *other))
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        match self {
+            Answer::Text(s) => s == other.trim(),
+            _ => self.as_number() == parse_number(other.trim()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_numeric_equality() {
+        assert_eq!(Answer::from(42u32), Answer::from(42i64));
+        assert_eq!(Answer::from(42u128), 42);
+        assert_eq!(Answer::from(-7i128), Answer::from(-7i32));
+        assert_ne!(Answer::from(-7i64), Answer::from(7u64));
+        assert_eq!(Answer::from(0i64), Answer::from(0u64));
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("123".parse::<Answer>().unwrap(), 123);
+        assert_eq!(" -5\n".parse::<Answer>().unwrap(), -5);
+        assert_eq!(
+            "340282366920938463463374607431768211455"
+                .parse::<Answer>()
+                .unwrap(),
+            u128::MAX
+        );
+        assert_eq!(
+            "ABCDEF".parse::<Answer>().unwrap(),
+            Answer::Text("ABCDEF".to_string())
+        );
+        assert_eq!(Answer::from("12"), Answer::from(12));
+        assert_ne!(Answer::from("012"), Answer::from(12));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::from(-3i8).to_string(), "-3");
+        assert_eq!(Answer::from(u128::MAX).to_string(), u128::MAX.to_string());
+        assert_eq!(Answer::from("HELLO").to_string(), "HELLO");
+    }
+}
@@ -0,0 +1,208 @@
+use std::{
+    collections::BTreeMap,
+    fmt, fs,
+    io::{self, Write},
+    path::Path,
+};
+
+use serde::Deserialize;
+
+use crate::{Answer, AocError, Part, Solution, Year};
+
+/// The name of the answers manifest, which lives next to the puzzle inputs.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// How an answer compares to the known answer for its day and part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    /// The answer differs from the known one, which is kept for reporting.
+    Wrong(Answer),
+    /// There is no known answer to compare against.
+    Unknown,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::Wrong(expected) => write!(f, "wrong, expected {}", expected),
+            Verdict::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+#[derive(Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Known {
+    part_one: Option<Answer>,
+    part_two: Option<Answer>,
+}
+
+/// The known answers to the puzzle inputs of a year, keyed by day and part.
+///
+/// The manifest is a TOML table per day, named by its two digit number:
+///
+/// ```toml
+/// [07]
+/// part_one = 252656917
+/// part_two = 253499763
+/// ```
+#[derive(Default)]
+pub struct Answers {
+    days: BTreeMap<u8, Known>,
+}
+
+impl Answers {
+    /// Loads the manifest from an inputs directory. A missing manifest means
+    /// every answer is unknown, e.g. for inputs read through `AOC_INPUT_DIR`.
+    pub fn load(inputs_dir: &Path) -> Result<Self, AocError> {
+        let path = inputs_dir.join(ANSWERS_FILE);
+
+        match fs::read_to_string(&path) {
+            Ok(manifest) => {
+                Answers::parse(&manifest).map_err(|message| AocError::Answers { path, message })
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(source) => Err(AocError::Io { path, source }),
+        }
+    }
+
+    fn parse(manifest: &str) -> Result<Self, String> {
+        let tables: BTreeMap<String, Known> =
+            toml::from_str(manifest).map_err(|err| err.to_string())?;
+        let mut days = BTreeMap::new();
+
+        for (key, known) in tables {
+            match key.parse::<u8>() {
+                Ok(day @ 1..=25) if key.len() == 2 => days.insert(day, known),
+                _ => return Err(format!("`{}` is not a day between 01 and 25", key)),
+            };
+        }
+
+        Ok(Answers { days })
+    }
+
+    /// The known answer to a part of a day, if there is one.
+    pub fn get(&self, day: u8, part: Part) -> Option<&Answer> {
+        let known = self.days.get(&day)?;
+
+        match part {
+            Part::One => known.part_one.as_ref(),
+            Part::Two => known.part_two.as_ref(),
+        }
+    }
+
+    /// The days the manifest has answers for.
+    pub fn days(&self) -> impl Iterator<Item = u8> + '_ {
+        self.days.keys().copied()
+    }
+
+    pub fn check(&self, day: u8, part: Part, answer: &Answer) -> Verdict {
+        match self.get(day, part) {
+            Some(expected) if expected == answer => Verdict::Correct,
+            Some(expected) => Verdict::Wrong(expected.clone()),
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+/// Solves a day against its puzzle input and asserts every answer the
+/// manifest knows for it, so each day needs a single test for its input.
+///
+/// Puzzle inputs are personal and may not be there, e.g. in a fresh clone, so
+/// a missing input, or an encrypted one without the key to read it, skips the
+/// check with a message instead of failing. An input that fails to decrypt
+/// still fails.
+pub fn assert_known_answers<S: Solution>(year: &Year) {
+    let input = match year.read_input(&format!("{:02}.txt", S::DAY)) {
+        Ok(input) => input,
+        Err(err @ (AocError::MissingInput { .. } | AocError::MissingKey(_))) => {
+            // Straight to stderr, as the test harness swallows `eprintln!`
+            // of passing tests.
+            let _ = writeln!(io::stderr(), "skipped known answers, {}", err);
+            return;
+        }
+        Err(err) => panic!("{}", err),
+    };
+
+    let answers = year.answers().unwrap();
+    let parts = Part::ALL.map(|part| (part, answers.get(S::DAY, part)));
+    assert!(
+        parts.iter().any(|(_, expected)| expected.is_some()),
+        "no known answers for day {:02} in {}",
+        S::DAY,
+        ANSWERS_FILE
+    );
+
+    let report = (crate::Day::new::<S>().solve)(&input, None);
+
+    for (part, expected) in parts {
+        if let Some(expected) = expected {
+            let answer = report.part(part).and_then(|outcome| outcome.answer());
+            assert_eq!(answer, Some(expected), "day {:02} {}", S::DAY, part);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(
+            "[07]\npart_one = 252656917\n\n[25]\npart_one = \"589036\"\npart_two = \"Merry Christmas!\"\n",
+        )
+        .unwrap();
+
+        assert_eq!(answers.get(7, Part::One), Some(&Answer::from(252656917)));
+        assert_eq!(answers.get(7, Part::Two), None);
+        assert_eq!(answers.get(25, Part::One), Some(&Answer::from(589036)));
+        assert_eq!(
+            answers.get(25, Part::Two),
+            Some(&Answer::from("Merry Christmas!"))
+        );
+        assert_eq!(answers.get(8, Part::One), None);
+
+        assert!(Answers::parse("[7]\npart_one = 1\n").is_err());
+        assert!(Answers::parse("[26]\npart_one = 1\n").is_err());
+        assert!(Answers::parse("[01]\npart_three = 1\n").is_err());
+    }
+
+    #[test]
+    fn test_missing_input_is_skipped() {
+        struct Day01;
+
+        impl Solution for Day01 {
+            const DAY: u8 = 1;
+            type Input<'a> = ();
+            fn parse(_input: &str) {}
+            fn part_one(_input: &()) -> Answer {
+                unreachable!()
+            }
+            fn part_two(_input: &()) -> Answer {
+                unreachable!()
+            }
+        }
+
+        let year = Year {
+            number: 2023,
+            dir: concat!(env!("CARGO_MANIFEST_DIR"), "/no-such-year"),
+            days: &[],
+        };
+        assert_known_answers::<Day01>(&year);
+    }
+
+    #[test]
+    fn test_check() {
+        let answers = Answers::parse("[01]\npart_one = 142\n").unwrap();
+
+        assert_eq!(answers.check(1, Part::One, &142.into()), Verdict::Correct);
+        assert_eq!(
+            answers.check(1, Part::One, &143.into()),
+            Verdict::Wrong(142.into())
+        );
+        assert_eq!(answers.check(1, Part::Two, &281.into()), Verdict::Unknown);
+    }
+}
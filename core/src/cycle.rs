@@ -0,0 +1,148 @@
+//! Finding where a simulation starts repeating, for puzzles that ask for the
+//! state after far more steps than could be simulated.
+
+use std::{collections::HashMap, hash::Hash};
+
+/// Where a sequence of states repeats: the state after `start + period`
+/// steps is the state after `start` steps, and it goes around from there.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    /// The first step with the same state as step `n`.
+    pub fn equivalent_step(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.period
+        }
+    }
+}
+
+/// Finds the cycle of the states reached by applying `step` over and over,
+/// starting from `initial` as step 0.
+///
+/// Every state is kept until the first repeat, so the states should be small
+/// or the cycle short.
+pub fn find_cycle<S: Clone + Hash + Eq>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut states = States::new(initial);
+    loop {
+        if let Some(cycle) = states.advance(&mut step) {
+            return cycle;
+        }
+    }
+}
+
+/// The state after `n` steps from `initial`, which only simulates up to the
+/// first repeat and jumps over every whole period after it.
+pub fn nth_state<S: Clone + Hash + Eq>(initial: S, mut step: impl FnMut(&S) -> S, n: usize) -> S {
+    let mut states = States::new(initial);
+    while states.history.len() <= n {
+        if let Some(cycle) = states.advance(&mut step) {
+            return states.history.swap_remove(cycle.equivalent_step(n));
+        }
+    }
+
+    states.history.swap_remove(n)
+}
+
+// The states seen so far, in order, and the step each was first seen at.
+struct States<S> {
+    history: Vec<S>,
+    seen: HashMap<S, usize>,
+}
+
+impl<S: Clone + Hash + Eq> States<S> {
+    fn new(initial: S) -> Self {
+        States {
+            seen: HashMap::from([(initial.clone(), 0)]),
+            history: vec![initial],
+        }
+    }
+
+    // Takes one more step, returning the cycle once a state comes back.
+    fn advance(&mut self, step: &mut impl FnMut(&S) -> S) -> Option<Cycle> {
+        let next = step(self.history.last().unwrap());
+        let n = self.history.len();
+
+        if let Some(&start) = self.seen.get(&next) {
+            return Some(Cycle {
+                start,
+                period: n - start,
+            });
+        }
+
+        self.seen.insert(next.clone(), n);
+        self.history.push(next);
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0, 1, 2, then around 3, 4, 5, 6.
+    fn step(n: &u64) -> u64 {
+        if *n < 3 {
+            n + 1
+        } else {
+            3 + (n - 2) % 4
+        }
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        assert_eq!(
+            find_cycle(0, step),
+            Cycle {
+                start: 3,
+                period: 4
+            }
+        );
+        assert_eq!(
+            find_cycle(5, step),
+            Cycle {
+                start: 0,
+                period: 4
+            }
+        );
+        assert_eq!(
+            find_cycle(0, |n| (n + 1) % 3),
+            Cycle {
+                start: 0,
+                period: 3
+            }
+        );
+    }
+
+    #[test]
+    fn test_nth_state() {
+        let brute = |mut state: u64, n: usize| {
+            for _ in 0..n {
+                state = step(&state);
+            }
+            state
+        };
+
+        for n in [0, 2, 3, 6, 7, 8, 100, 101] {
+            assert_eq!(nth_state(0, step, n), brute(0, n), "{}", n);
+        }
+        assert_eq!(
+            nth_state(0, step, 1_000_000_000),
+            3 + (1_000_000_000 - 3) % 4
+        );
+        assert_eq!(nth_state(0, |n| (n + 1) % 3, 1_000_000_000), 1);
+        assert_eq!(
+            Cycle {
+                start: 3,
+                period: 4
+            }
+            .equivalent_step(1_000_000_000),
+            4
+        );
+    }
+}
@@ -0,0 +1,109 @@
+use std::{error::Error, fmt, io, path::PathBuf};
+
+/// Errors raised while loading a puzzle or example input.
+pub enum AocError {
+    /// The input file doesn't exist, usually because it hasn't been downloaded yet.
+    MissingInput { day: Option<u8>, path: PathBuf },
+    /// The input file exists but couldn't be read.
+    Io { path: PathBuf, source: io::Error },
+    /// A file couldn't be written.
+    Write { path: PathBuf, source: io::Error },
+    /// A file that would be created already exists.
+    AlreadyExists(PathBuf),
+    /// The input couldn't be read from standard input.
+    Stdin(io::Error),
+    /// The command line arguments couldn't be understood.
+    Usage(String),
+    /// The answers manifest exists but isn't valid.
+    Answers { path: PathBuf, message: String },
+    /// The examples manifest exists but isn't valid.
+    Examples { path: PathBuf, message: String },
+    /// An external command, such as `cargo`, couldn't be started.
+    Command { program: String, source: io::Error },
+    /// An input is only there encrypted, and there is no key to read it.
+    MissingKey(PathBuf),
+    /// The key for encrypted inputs isn't valid.
+    InvalidKey(String),
+    /// An encrypted input couldn't be decrypted.
+    Decrypt { path: PathBuf, reason: String },
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::MissingInput { day, path } => {
+                match day {
+                    Some(day) => write!(f, "missing input for day {:02}: ", day)?,
+                    None => write!(f, "missing input: ")?,
+                }
+                write!(f, "{} does not exist", path.display())?;
+
+                match (path.file_name(), path.parent()) {
+                    (Some(file), Some(dir)) => write!(
+                        f,
+                        " (expected {} in {})",
+                        file.to_string_lossy(),
+                        dir.display()
+                    ),
+                    _ => Ok(()),
+                }
+            }
+            AocError::Io { path, source } => {
+                write!(f, "failed to read {}: {}", path.display(), source)
+            }
+            AocError::Write { path, source } => {
+                write!(f, "failed to write {}: {}", path.display(), source)
+            }
+            AocError::AlreadyExists(path) => {
+                write!(f, "refusing to overwrite {}", path.display())
+            }
+            AocError::Stdin(source) => write!(f, "failed to read standard input: {}", source),
+            AocError::Usage(message) => write!(f, "{}", message),
+            AocError::Answers { path, message } => {
+                write!(f, "invalid answers in {}: {}", path.display(), message)
+            }
+            AocError::Examples { path, message } => {
+                write!(f, "invalid examples in {}: {}", path.display(), message)
+            }
+            AocError::Command { program, source } => {
+                write!(f, "failed to run {}: {}", program, source)
+            }
+            AocError::MissingKey(path) => write!(
+                f,
+                "{} is encrypted, but there is no key: set {} or run `aoc inputs encrypt` to create one",
+                path.display(),
+                crate::vault::KEY_VAR
+            ),
+            AocError::InvalidKey(message) => write!(f, "invalid input key: {}", message),
+            AocError::Decrypt { path, reason } => {
+                write!(f, "failed to decrypt {}: {}", path.display(), reason)
+            }
+        }
+    }
+}
+
+// `unwrap()` prints the `Debug` output, so keep it as readable as `Display`.
+impl fmt::Debug for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl Error for AocError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AocError::Io { source, .. }
+            | AocError::Write { source, .. }
+            | AocError::Stdin(source)
+            | AocError::Command { source, .. } => Some(source),
+            AocError::MissingInput { .. }
+            | AocError::AlreadyExists(_)
+            | AocError::Usage(_)
+            | AocError::Answers { .. }
+            | AocError::Examples { .. }
+            | AocError::MissingKey(_)
+            | AocError::InvalidKey(_)
+            | AocError::Decrypt { .. } => None,
+        }
+    }
+}
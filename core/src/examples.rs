@@ -0,0 +1,351 @@
+//! The examples from the puzzle texts and the answers they give, listed in a
+//! manifest next to the example inputs instead of a test per example in every
+//! day. The year crates turn the manifest into one test per example at build
+//! time with [`write_tests`].
+
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, BTreeSet},
+    fmt, fs, io,
+    path::Path,
+};
+
+use serde::{de, Deserialize, Deserializer};
+
+use crate::{Answer, AocError, Part, Solution, Year};
+
+/// The name of the examples manifest, which lives next to the example inputs.
+pub const EXAMPLES_FILE: &str = "examples.toml";
+
+/// Numbers of a puzzle that an example changes, such as the number of steps
+/// to take, by name.
+///
+/// Every parameter has to be read by the solution, so a misspelt name fails
+/// the example instead of being ignored.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(transparent)]
+pub struct Params {
+    values: BTreeMap<String, i64>,
+    #[serde(skip)]
+    used: RefCell<BTreeSet<String>>,
+}
+
+impl Params {
+    pub fn new<'a>(values: impl IntoIterator<Item = (&'a str, i64)>) -> Self {
+        Params {
+            values: values
+                .into_iter()
+                .map(|(name, value)| (name.to_string(), value))
+                .collect(),
+            used: RefCell::default(),
+        }
+    }
+
+    /// The value of a parameter, or `default` if the example doesn't set it.
+    ///
+    /// # Panics
+    ///
+    /// If the value doesn't fit in a `T`.
+    pub fn get_or<T: TryFrom<i64>>(&self, name: &str, default: T) -> T {
+        self.used.borrow_mut().insert(name.to_string());
+
+        match self.values.get(name) {
+            Some(&value) => T::try_from(value)
+                .unwrap_or_else(|_| panic!("parameter {} = {} is out of range", name, value)),
+            None => default,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// The parameters the solution never read.
+    pub fn unused(&self) -> Vec<&str> {
+        let used = self.used.borrow();
+        self.values
+            .keys()
+            .filter(|name| !used.contains(*name))
+            .map(String::as_str)
+            .collect()
+    }
+}
+
+impl fmt::Display for Params {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (name, value)) in self.values.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{} = {}", name, value)?;
+        }
+        Ok(())
+    }
+}
+
+/// An example input, the part it's for and the answer it gives.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Example {
+    /// The file in `src/test_inputs` of the year.
+    pub file: String,
+    #[serde(deserialize_with = "deserialize_part")]
+    pub part: Part,
+    #[serde(default)]
+    pub params: Params,
+    pub expected: Answer,
+}
+
+fn deserialize_part<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Part, D::Error> {
+    match u8::deserialize(deserializer)? {
+        1 => Ok(Part::One),
+        2 => Ok(Part::Two),
+        n => Err(de::Error::custom(format!(
+            "{} is not a part, expected 1 or 2",
+            n
+        ))),
+    }
+}
+
+impl Example {
+    // The name of the test for the example, unique among the examples of
+    // its day unless two examples are exactly the same.
+    fn test_name(&self) -> String {
+        let stem = self.file.strip_suffix(".txt").unwrap_or(&self.file);
+        let part = match self.part {
+            Part::One => "one",
+            Part::Two => "two",
+        };
+        let mut name = format!("part_{}_{}", part, stem);
+        for (param, value) in &self.params.values {
+            name += &format!("_{}_{}", param, value);
+        }
+
+        name.chars()
+            .map(|c| match c {
+                'a'..='z' | '0'..='9' | '_' => c,
+                'A'..='Z' => c.to_ascii_lowercase(),
+                _ => '_',
+            })
+            .collect()
+    }
+}
+
+/// The examples of a year, keyed by day, in the order of the manifest.
+///
+/// The manifest is an array of tables per day, named by its two digit number:
+///
+/// ```toml
+/// [[11]]
+/// file = "11_one.txt"
+/// part = 2
+/// params = { expansion = 10 }
+/// expected = 1030
+/// ```
+#[derive(Debug, Default)]
+pub struct Examples {
+    days: BTreeMap<u8, Vec<Example>>,
+}
+
+impl Examples {
+    /// Loads the manifest from an example inputs directory. A missing
+    /// manifest means there are no examples.
+    pub fn load(examples_dir: &Path) -> Result<Self, AocError> {
+        let path = examples_dir.join(EXAMPLES_FILE);
+
+        match fs::read_to_string(&path) {
+            Ok(manifest) => {
+                Examples::parse(&manifest).map_err(|message| AocError::Examples { path, message })
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Examples::default()),
+            Err(source) => Err(AocError::Io { path, source }),
+        }
+    }
+
+    fn parse(manifest: &str) -> Result<Self, String> {
+        let tables: BTreeMap<String, Vec<Example>> =
+            toml::from_str(manifest).map_err(|err| err.to_string())?;
+        let mut days = BTreeMap::new();
+
+        // Day 00 is the template new days are scaffolded from.
+        for (key, examples) in tables {
+            match key.parse::<u8>() {
+                Ok(day @ 0..=25) if key.len() == 2 => days.insert(day, examples),
+                _ => return Err(format!("`{}` is not a day between 00 and 25", key)),
+            };
+        }
+
+        Ok(Examples { days })
+    }
+
+    /// The examples of a day, none if the manifest has no table for it.
+    pub fn day(&self, day: u8) -> &[Example] {
+        self.days.get(&day).map_or(&[], Vec::as_slice)
+    }
+
+    /// The days the manifest has examples for.
+    pub fn days(&self) -> impl Iterator<Item = u8> + '_ {
+        self.days.keys().copied()
+    }
+
+    // A module per day with a test per example, which calls
+    // `crate::assert_example` with the solution of the day and the index of
+    // the example.
+    fn tests(&self) -> String {
+        let mut code = String::new();
+
+        for (&day, examples) in &self.days {
+            code += &format!("mod day{:02} {{\n", day);
+            let mut names = BTreeSet::new();
+            for (i, example) in examples.iter().enumerate() {
+                let mut name = example.test_name();
+                if !names.insert(name.clone()) {
+                    name += &format!("_{}", i);
+                }
+                code += &format!(
+                    "    #[test]\n    fn {}() {{\n        \
+                     crate::assert_example::<crate::days::day{:02}::Day{:02}>({});\n    }}\n",
+                    name, day, day, i
+                );
+            }
+            code += "}\n";
+        }
+
+        code
+    }
+}
+
+/// Writes the tests for the examples manifest in `examples_dir` to `out`, for
+/// the build script of a year to include into its crate. Cargo is told to
+/// build again whenever the manifest changes.
+pub fn write_tests(examples_dir: &Path, out: &Path) -> Result<(), AocError> {
+    println!(
+        "cargo:rerun-if-changed={}",
+        examples_dir.join(EXAMPLES_FILE).display()
+    );
+    let examples = Examples::load(examples_dir)?;
+
+    fs::write(out, examples.tests()).map_err(|source| AocError::Write {
+        path: out.to_path_buf(),
+        source,
+    })
+}
+
+/// Solves the example at `index` among the examples of a day and asserts its
+/// answer. The tests from [`write_tests`] call this.
+pub fn assert_example<S: Solution>(year: &Year, index: usize) {
+    let examples = year.examples().unwrap();
+    let example = &examples.day(S::DAY)[index];
+    let input = year.read_example(&example.file).unwrap();
+
+    let parsed = S::parse(&input);
+    let answer = match example.part {
+        Part::One => S::part_one_with(&parsed, &example.params),
+        Part::Two => S::part_two_with(&parsed, &example.params),
+    };
+
+    let with = if example.params.is_empty() {
+        String::new()
+    } else {
+        format!(" with {}", example.params)
+    };
+    assert_eq!(
+        answer,
+        example.expected,
+        "day {:02} {} of {}{}",
+        S::DAY,
+        example.part,
+        example.file,
+        with
+    );
+    assert!(
+        example.params.unused().is_empty(),
+        "day {:02} {} doesn't use {:?}",
+        S::DAY,
+        example.part,
+        example.params.unused()
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic::AssertUnwindSafe;
+
+    use super::*;
+
+    const MANIFEST: &str = r#"
+[[10]]
+file = "10_two_a.txt"
+part = 2
+expected = 4
+
+[[11]]
+file = "11_one.txt"
+part = 1
+expected = 374
+
+[[11]]
+file = "11_one.txt"
+part = 2
+params = { expansion = 10 }
+expected = 1030
+
+[[11]]
+file = "11_one.txt"
+part = 2
+params = { expansion = 10 }
+expected = 1030
+"#;
+
+    #[test]
+    fn test_parse() {
+        let examples = Examples::parse(MANIFEST).unwrap();
+
+        assert_eq!(examples.days().collect::<Vec<_>>(), [10, 11]);
+        assert_eq!(examples.day(11).len(), 3);
+        assert!(examples.day(12).is_empty());
+
+        let example = &examples.day(11)[1];
+        assert_eq!(example.file, "11_one.txt");
+        assert_eq!(example.part, Part::Two);
+        assert_eq!(example.expected, Answer::from(1030));
+        assert_eq!(example.params.get_or("expansion", 2), 10);
+        assert_eq!(example.params.get_or("other", 2), 2);
+        assert!(examples.day(10)[0].params.is_empty());
+
+        assert!(Examples::parse("[[7]]\nfile = \"a\"\npart = 1\nexpected = 1\n").is_err());
+        assert!(Examples::parse("[[07]]\nfile = \"a\"\npart = 3\nexpected = 1\n").is_err());
+        assert!(Examples::parse("[[07]]\nfile = \"a\"\npart = 1\n").is_err());
+        assert!(
+            Examples::parse("[[07]]\nfile = \"a\"\npart = 1\nexpected = 1\nsteps = 6\n").is_err()
+        );
+    }
+
+    #[test]
+    fn test_params() {
+        let params = Params::new([("steps", 6), ("size", -1)]);
+
+        assert_eq!(params.to_string(), "size = -1, steps = 6");
+        assert_eq!(params.get_or::<usize>("steps", 64), 6);
+        assert_eq!(params.unused(), ["size"]);
+        let negative =
+            std::panic::catch_unwind(AssertUnwindSafe(|| params.get_or::<usize>("size", 0)));
+        assert!(negative.is_err());
+    }
+
+    #[test]
+    fn test_tests() {
+        let tests = Examples::parse(MANIFEST).unwrap().tests();
+
+        assert!(tests.starts_with(
+            "mod day10 {\n    #[test]\n    fn part_two_10_two_a() {\n        \
+             crate::assert_example::<crate::days::day10::Day10>(0);\n    }\n}\n"
+        ));
+        assert!(tests.contains("fn part_one_11_one() {"));
+        assert!(tests.contains("fn part_two_11_one_expansion_10() {"));
+        assert!(tests.contains(
+            "fn part_two_11_one_expansion_10_2() {\n        \
+             crate::assert_example::<crate::days::day11::Day11>(2);"
+        ));
+    }
+}
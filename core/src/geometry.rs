@@ -0,0 +1,308 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A number that can be a coordinate of a [`Point2`] or [`Point3`], signed or
+/// unsigned.
+pub trait Coord:
+    Copy + Ord + Default + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+    /// The distance between two coordinates, which can't overflow for
+    /// unsigned ones.
+    fn distance(self, other: Self) -> Self;
+}
+
+macro_rules! coord {
+    ($($t:ty),*) => {
+        $(impl Coord for $t {
+            fn distance(self, other: Self) -> Self {
+                self.max(other) - self.min(other)
+            }
+        })*
+    };
+}
+
+coord!(i32, i64, i128, isize, u32, u64, usize);
+
+/// A point or vector in 2D, where `y` grows downwards like the rows of a
+/// puzzle input.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point2<T = i64> {
+    pub x: T,
+    pub y: T,
+}
+
+/// A point or vector in 3D.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point3<T = i64> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Point2 { x, y }
+    }
+}
+
+impl<T: Coord> Point2<T> {
+    pub fn manhattan(self, other: Self) -> T {
+        self.x.distance(other.x) + self.y.distance(other.y)
+    }
+}
+
+impl Point2<usize> {
+    /// The neighbouring point in `direction`, or `None` if it would be left of
+    /// or above zero.
+    pub fn step(self, direction: impl Into<Point2<isize>>) -> Option<Self> {
+        let delta = direction.into();
+        Some(Point2::new(
+            self.x.checked_add_signed(delta.x)?,
+            self.y.checked_add_signed(delta.y)?,
+        ))
+    }
+}
+
+impl<T> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Point3 { x, y, z }
+    }
+}
+
+impl<T: Coord> Point3<T> {
+    pub fn manhattan(self, other: Self) -> T {
+        self.x.distance(other.x) + self.y.distance(other.y) + self.z.distance(other.z)
+    }
+}
+
+// Both points get the same component-wise operators, and multiplication by a
+// scalar.
+macro_rules! ops {
+    ($point:ident { $($field:ident),* }) => {
+        impl<T: Coord> Add for $point<T> {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                $point { $($field: self.$field + other.$field),* }
+            }
+        }
+
+        impl<T: Coord> Sub for $point<T> {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                $point { $($field: self.$field - other.$field),* }
+            }
+        }
+
+        impl<T: Coord> Mul<T> for $point<T> {
+            type Output = Self;
+
+            fn mul(self, scalar: T) -> Self {
+                $point { $($field: self.$field * scalar),* }
+            }
+        }
+
+        impl<T: Coord + Neg<Output = T>> Neg for $point<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                $point { $($field: -self.$field),* }
+            }
+        }
+
+        impl<T: Coord> AddAssign for $point<T> {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl<T: Coord> SubAssign for $point<T> {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+    };
+}
+
+ops!(Point2 { x, y });
+ops!(Point3 { x, y, z });
+
+// Grid positions are `(x, y)` tuples.
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Point2 { x, y }
+    }
+}
+
+impl<T> From<Point2<T>> for (T, T) {
+    fn from(point: Point2<T>) -> Self {
+        (point.x, point.y)
+    }
+}
+
+/// One of the four directions along the axes, in clockwise order from up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+/// One of the eight directions to a neighbour, diagonals included, in
+/// clockwise order from up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction4 {
+    pub const ALL: [Direction4; 4] = [
+        Direction4::Up,
+        Direction4::Right,
+        Direction4::Down,
+        Direction4::Left,
+    ];
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    /// The step to the neighbour in this direction, as `(dx, dy)`.
+    pub fn delta(self) -> Point2<isize> {
+        Direction8::from(self).delta()
+    }
+}
+
+impl Direction8 {
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    /// The next direction clockwise, an eighth of a turn.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// The next direction counterclockwise, an eighth of a turn.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    /// The step to the neighbour in this direction, as `(dx, dy)`.
+    pub fn delta(self) -> Point2<isize> {
+        let (x, y) = match self {
+            Direction8::Up => (0, -1),
+            Direction8::UpRight => (1, -1),
+            Direction8::Right => (1, 0),
+            Direction8::DownRight => (1, 1),
+            Direction8::Down => (0, 1),
+            Direction8::DownLeft => (-1, 1),
+            Direction8::Left => (-1, 0),
+            Direction8::UpLeft => (-1, -1),
+        };
+        Point2::new(x, y)
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(direction: Direction4) -> Self {
+        Direction8::ALL[direction as usize * 2]
+    }
+}
+
+impl From<Direction4> for Point2<isize> {
+    fn from(direction: Direction4) -> Self {
+        direction.delta()
+    }
+}
+
+impl From<Direction8> for Point2<isize> {
+    fn from(direction: Direction8) -> Self {
+        direction.delta()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_points() {
+        let (a, b) = (Point2::new(1, -2), Point2::new(-3, 4));
+
+        assert_eq!(a + b, Point2::new(-2, 2));
+        assert_eq!(a - b, Point2::new(4, -6));
+        assert_eq!(-a * 3, Point2::new(-3, 6));
+        assert_eq!(a.manhattan(b), 10);
+        assert_eq!(Point2::new(5_usize, 1).manhattan(Point2::new(2, 3)), 5);
+        assert_eq!(
+            Point3::new(1, 2, 3) + Point3::new(1, 1, 1) * 2,
+            Point3::new(3, 4, 5)
+        );
+        assert_eq!(Point3::new(0_u64, 7, 2).manhattan(Point3::new(3, 2, 2)), 8);
+
+        let mut c = a;
+        c += b;
+        c -= a;
+        assert_eq!(c, b);
+        assert_eq!(<(i64, i64)>::from(c), (-3, 4));
+    }
+
+    #[test]
+    fn test_step() {
+        let point = Point2::new(0_usize, 2);
+
+        assert_eq!(point.step(Direction4::Up), Some(Point2::new(0, 1)));
+        assert_eq!(point.step(Direction8::DownRight), Some(Point2::new(1, 3)));
+        assert_eq!(point.step(Direction4::Left), None);
+    }
+
+    #[test]
+    fn test_directions() {
+        use Direction4::*;
+
+        assert_eq!(Up.turn_right(), Right);
+        assert_eq!(Up.turn_left(), Left);
+        assert_eq!(Left.opposite(), Right);
+        assert_eq!(Down.delta(), Point2::new(0, 1));
+        for direction in Direction4::ALL {
+            assert_eq!(direction.opposite().delta(), -direction.delta());
+            assert_eq!(direction.turn_right().turn_left(), direction);
+        }
+
+        assert_eq!(Direction8::Up.turn_left(), Direction8::UpLeft);
+        assert_eq!(Direction8::UpLeft.turn_right(), Direction8::Up);
+        assert_eq!(Direction8::DownLeft.opposite(), Direction8::UpRight);
+        assert_eq!(Direction8::from(Left), Direction8::Left);
+        for direction in Direction8::ALL {
+            assert_eq!(direction.opposite().delta(), -direction.delta());
+        }
+    }
+}
@@ -0,0 +1,382 @@
+//! Undirected graphs of named nodes, for puzzles that give a network as
+//! lines of `name: neighbour neighbour ...`.
+
+use std::{
+    collections::{BinaryHeap, HashMap, HashSet},
+    fmt::Write,
+};
+
+/// An undirected graph without weights, where every node is named by a slice
+/// of the input.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Graph<'a> {
+    names: Vec<&'a str>,
+    index: HashMap<&'a str, usize>,
+    adjacency: Vec<Vec<usize>>,
+}
+
+/// A way to split a graph in two, and the edges between the halves.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cut<'a> {
+    pub edges: Vec<(&'a str, &'a str)>,
+    pub partition: (Vec<&'a str>, Vec<&'a str>),
+}
+
+impl<'a> Graph<'a> {
+    pub fn new() -> Self {
+        Graph::default()
+    }
+
+    // The index of a node, adding it without edges if it is new.
+    fn node(&mut self, name: &'a str) -> usize {
+        if let Some(&i) = self.index.get(name) {
+            return i;
+        }
+
+        self.index.insert(name, self.names.len());
+        self.names.push(name);
+        self.adjacency.push(Vec::new());
+        self.names.len() - 1
+    }
+
+    /// Adds a node without edges, unless it's already there.
+    pub fn add_node(&mut self, name: &'a str) {
+        self.node(name);
+    }
+
+    /// Connects two nodes, adding them if they are new. Returns `false` if
+    /// they were already connected; loops are ignored.
+    pub fn add_edge(&mut self, a: &'a str, b: &'a str) -> bool {
+        let (a, b) = (self.node(a), self.node(b));
+        if a == b || self.adjacency[a].contains(&b) {
+            return false;
+        }
+
+        self.adjacency[a].push(b);
+        self.adjacency[b].push(a);
+        true
+    }
+
+    /// Disconnects two nodes, returning `false` if they weren't connected.
+    pub fn remove_edge(&mut self, a: &str, b: &str) -> bool {
+        let (Some(&a), Some(&b)) = (self.index.get(a), self.index.get(b)) else {
+            return false;
+        };
+        let Some(at) = self.adjacency[a].iter().position(|&n| n == b) else {
+            return false;
+        };
+
+        self.adjacency[a].swap_remove(at);
+        self.adjacency[b].retain(|&n| n != a);
+        true
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.adjacency.iter().map(Vec::len).sum::<usize>() / 2
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.index.contains_key(name)
+    }
+
+    /// The nodes in the order they were added.
+    pub fn nodes(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.names.iter().copied()
+    }
+
+    /// The nodes connected to `name`, none if it isn't in the graph.
+    pub fn neighbours(&self, name: &str) -> impl Iterator<Item = &'a str> + '_ {
+        self.index
+            .get(name)
+            .into_iter()
+            .flat_map(|&i| &self.adjacency[i])
+            .map(|&n| self.names[n])
+    }
+
+    /// Every edge once, with its nodes in the order they were added.
+    pub fn edges(&self) -> impl Iterator<Item = (&'a str, &'a str)> + '_ {
+        self.adjacency.iter().enumerate().flat_map(move |(a, ns)| {
+            ns.iter()
+                .filter(move |&&b| a < b)
+                .map(move |&b| (self.names[a], self.names[b]))
+        })
+    }
+
+    /// The groups of nodes that are connected to each other, each in the
+    /// order its nodes were added.
+    pub fn connected_components(&self) -> Vec<Vec<&'a str>> {
+        let mut component = vec![None; self.names.len()];
+        let mut components: Vec<Vec<usize>> = Vec::new();
+
+        for start in 0..self.names.len() {
+            if component[start].is_some() {
+                continue;
+            }
+
+            let id = components.len();
+            component[start] = Some(id);
+            let mut stack = vec![start];
+            let mut members = Vec::new();
+            while let Some(node) = stack.pop() {
+                members.push(node);
+                for &n in &self.adjacency[node] {
+                    if component[n].is_none() {
+                        component[n] = Some(id);
+                        stack.push(n);
+                    }
+                }
+            }
+            members.sort_unstable();
+            components.push(members);
+        }
+
+        components
+            .into_iter()
+            .map(|members| members.into_iter().map(|n| self.names[n]).collect())
+            .collect()
+    }
+
+    /// The split of the graph in two that cuts the fewest edges, found with
+    /// the Stoer–Wagner algorithm, or `None` with fewer than two nodes. A
+    /// graph that isn't connected has a cut without edges.
+    pub fn min_cut(&self) -> Option<Cut<'a>> {
+        let n = self.names.len();
+        if n < 2 {
+            return None;
+        }
+
+        // Nodes are merged as the algorithm goes, adding up the weights of
+        // the edges they had to every other node.
+        let mut weights: Vec<HashMap<usize, usize>> = self
+            .adjacency
+            .iter()
+            .map(|ns| ns.iter().map(|&b| (b, 1)).collect())
+            .collect();
+        let mut merged: Vec<Vec<usize>> = (0..n).map(|a| vec![a]).collect();
+        let mut active: Vec<usize> = (0..n).collect();
+        let mut best: Option<(usize, Vec<usize>)> = None;
+
+        while active.len() > 1 {
+            let (s, t, cut) = maximum_adjacency_phase(&weights, &active, n);
+            if best.as_ref().is_none_or(|(weight, _)| cut < *weight) {
+                best = Some((cut, merged[t].clone()));
+            }
+
+            // Merge t into s.
+            for (b, w) in std::mem::take(&mut weights[t]) {
+                weights[b].remove(&t);
+                if b != s {
+                    *weights[s].entry(b).or_default() += w;
+                    *weights[b].entry(s).or_default() += w;
+                }
+            }
+            let nodes = std::mem::take(&mut merged[t]);
+            merged[s].extend(nodes);
+            active.retain(|&a| a != t);
+        }
+
+        let (_, side) = best?;
+        let mut in_side = vec![false; n];
+        for &a in &side {
+            in_side[a] = true;
+        }
+
+        let edges = self
+            .edges()
+            .filter(|(a, b)| in_side[self.index[a]] != in_side[self.index[b]])
+            .collect();
+        let (inside, outside) = self.nodes().partition(|name| in_side[self.index[name]]);
+        Some(Cut {
+            edges,
+            partition: (inside, outside),
+        })
+    }
+
+    /// The graph in Graphviz DOT, with the `highlighted` edges drawn in red,
+    /// such as the edges of a cut.
+    pub fn to_dot(&self, highlighted: &[(&str, &str)]) -> String {
+        let highlighted: HashSet<(&str, &str)> = highlighted
+            .iter()
+            .flat_map(|&(a, b)| [(a, b), (b, a)])
+            .collect();
+
+        let mut dot = String::from("graph {\n");
+        for name in self
+            .nodes()
+            .filter(|name| self.neighbours(name).next().is_none())
+        {
+            writeln!(dot, "    {:?};", name).unwrap();
+        }
+        for (a, b) in self.edges() {
+            let style = if highlighted.contains(&(a, b)) {
+                " [color=red, penwidth=3]"
+            } else {
+                ""
+            };
+            writeln!(dot, "    {:?} -- {:?}{};", a, b, style).unwrap();
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+// One phase of Stoer–Wagner: adds the active nodes one at a time, always the
+// one most strongly connected to those already added. Returns the last two
+// nodes added and the weight of cutting the last one off from the rest.
+fn maximum_adjacency_phase(
+    weights: &[HashMap<usize, usize>],
+    active: &[usize],
+    n: usize,
+) -> (usize, usize, usize) {
+    let mut connection = vec![0; n];
+    let mut added = vec![false; n];
+    let mut heap: BinaryHeap<(usize, usize)> = active.iter().map(|&a| (0, a)).collect();
+    let (mut previous, mut last) = (active[0], active[0]);
+    let mut count = 0;
+
+    while let Some((weight, a)) = heap.pop() {
+        if added[a] || weight < connection[a] {
+            continue;
+        }
+        added[a] = true;
+        (previous, last) = (last, a);
+        count += 1;
+        if count == active.len() {
+            break;
+        }
+
+        for (&b, &w) in &weights[a] {
+            if !added[b] {
+                connection[b] += w;
+                heap.push((connection[b], b));
+            }
+        }
+    }
+
+    (previous, last, connection[last])
+}
+
+impl<'a> FromIterator<(&'a str, &'a str)> for Graph<'a> {
+    fn from_iter<I: IntoIterator<Item = (&'a str, &'a str)>>(edges: I) -> Self {
+        let mut graph = Graph::new();
+        for (a, b) in edges {
+            graph.add_edge(a, b);
+        }
+        graph
+    }
+}
+
+impl<'a> From<&HashMap<&'a str, HashSet<&'a str>>> for Graph<'a> {
+    fn from(adjacency: &HashMap<&'a str, HashSet<&'a str>>) -> Self {
+        let mut graph = Graph::new();
+        for (&a, neighbours) in adjacency {
+            graph.add_node(a);
+            for &b in neighbours {
+                graph.add_edge(a, b);
+            }
+        }
+        graph
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Two triangles joined by two edges, and a lone pair.
+    fn graph() -> Graph<'static> {
+        [
+            ("a", "b"),
+            ("b", "c"),
+            ("c", "a"),
+            ("d", "e"),
+            ("e", "f"),
+            ("f", "d"),
+            ("a", "d"),
+            ("c", "f"),
+            ("x", "y"),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    #[test]
+    fn test_edges() {
+        let mut graph = graph();
+
+        assert_eq!((graph.node_count(), graph.edge_count()), (8, 9));
+        assert!(!graph.add_edge("b", "a"));
+        assert!(!graph.add_edge("a", "a"));
+        assert_eq!(graph.neighbours("a").collect::<Vec<_>>(), ["b", "c", "d"]);
+        assert!(graph.remove_edge("d", "a"));
+        assert!(!graph.remove_edge("a", "d"));
+        assert!(!graph.remove_edge("a", "zz"));
+        assert_eq!(graph.edge_count(), 8);
+        assert_eq!(graph.neighbours("zz").count(), 0);
+
+        let adjacency = HashMap::from([("a", HashSet::from(["b", "c"])), ("b", HashSet::new())]);
+        let graph = Graph::from(&adjacency);
+        assert_eq!((graph.node_count(), graph.edge_count()), (3, 2));
+    }
+
+    #[test]
+    fn test_connected_components() {
+        let mut graph = graph();
+        graph.add_node("z");
+
+        assert_eq!(
+            graph.connected_components(),
+            [
+                vec!["a", "b", "c", "d", "e", "f"],
+                vec!["x", "y"],
+                vec!["z"]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_min_cut() {
+        assert!(graph().min_cut().unwrap().edges.is_empty());
+        assert!(Graph::from_iter([("a", "a")]).min_cut().is_none());
+
+        // Two complete graphs of five nodes, joined by three edges.
+        let (left, right) = (["a", "b", "c", "d", "e"], ["v", "w", "x", "y", "z"]);
+        let mut graph = Graph::new();
+        for side in [left, right] {
+            for (i, a) in side.iter().enumerate() {
+                for b in &side[i + 1..] {
+                    graph.add_edge(a, b);
+                }
+            }
+        }
+        for (a, b) in [("a", "z"), ("b", "y"), ("c", "z")] {
+            graph.add_edge(a, b);
+        }
+
+        let cut = graph.min_cut().unwrap();
+        let mut edges = cut.edges.clone();
+        edges.sort();
+        assert_eq!(edges, [("a", "z"), ("b", "y"), ("c", "z")]);
+        let (mut inside, mut outside) = cut.partition;
+        if inside.contains(&"z") {
+            (inside, outside) = (outside, inside);
+        }
+        assert_eq!((inside, outside), (left.to_vec(), right.to_vec()));
+    }
+
+    #[test]
+    fn test_to_dot() {
+        let graph: Graph = [("a", "b"), ("b", "c")].into_iter().collect();
+        let mut lonely = graph.clone();
+        lonely.add_node("d");
+
+        assert_eq!(
+            lonely.to_dot(&[("c", "b")]),
+            "graph {\n    \"d\";\n    \"a\" -- \"b\";\n    \"b\" -- \"c\" [color=red, penwidth=3];\n}\n"
+        );
+    }
+}
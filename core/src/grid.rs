@@ -0,0 +1,368 @@
+use std::{
+    error::Error,
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+/// A position in a [`Grid`] as `(x, y)`, where `y` grows downwards.
+pub type Pos = (usize, usize);
+
+const NEIGHBOURS4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+const NEIGHBOURS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+];
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+/// A row of a grid that isn't as wide as the first one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RaggedRow {
+    pub row: usize,
+    pub width: usize,
+    pub expected: usize,
+}
+
+impl fmt::Display for RaggedRow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "row {} of the grid is {} wide, expected {}",
+            self.row, self.width, self.expected
+        )
+    }
+}
+
+impl Error for RaggedRow {}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Builds a grid from its rows, which must all be as wide as the first.
+    pub fn from_rows<R: IntoIterator<Item = T>>(
+        rows: impl IntoIterator<Item = R>,
+    ) -> Result<Self, RaggedRow> {
+        let mut grid = Grid {
+            width: 0,
+            height: 0,
+            cells: Vec::new(),
+        };
+
+        for (y, row) in rows.into_iter().enumerate() {
+            grid.cells.extend(row);
+            let width = grid.cells.len() - grid.width * y;
+            if y == 0 {
+                grid.width = width;
+            } else if width != grid.width {
+                return Err(RaggedRow {
+                    row: y,
+                    width,
+                    expected: grid.width,
+                });
+            }
+            grid.height += 1;
+        }
+
+        Ok(grid)
+    }
+
+    /// Parses a grid from the lines of a puzzle input, one cell per byte,
+    /// skipping blank lines at the end.
+    pub fn parse(input: &str, mut cell: impl FnMut(u8) -> T) -> Result<Self, RaggedRow> {
+        Self::from_rows(
+            input
+                .trim_end_matches(['\r', '\n'])
+                .lines()
+                .map(|line| line.bytes().map(&mut cell).collect::<Vec<_>>()),
+        )
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Pos) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos).then(|| &self[pos])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self[pos])
+        } else {
+            None
+        }
+    }
+
+    /// The position `(dx, dy)` away from `pos`, if it is inside the grid.
+    pub fn offset(&self, (x, y): Pos, (dx, dy): (isize, isize)) -> Option<Pos> {
+        let pos = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        self.contains(pos).then_some(pos)
+    }
+
+    /// The positions above, right of, below and left of `pos` that are inside
+    /// the grid, in that order.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        NEIGHBOURS4
+            .into_iter()
+            .filter_map(move |delta| self.offset(pos, delta))
+    }
+
+    /// The positions around `pos`, diagonals included, that are inside the
+    /// grid, clockwise from the top left.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        NEIGHBOURS8
+            .into_iter()
+            .filter_map(move |delta| self.offset(pos, delta))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    /// The cells of column `x`, from top to bottom.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} of a grid {} wide", x, self.width);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Every position of the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell of the grid with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The first position holding `value`, row by row.
+    pub fn find(&self, value: &T) -> Option<Pos>
+    where
+        T: PartialEq,
+    {
+        let i = self.cells.iter().position(|cell| cell == value)?;
+        Some((i % self.width, i / self.width))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// The grid mirrored along its diagonal, so rows become columns.
+    pub fn transposed(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.remap(self.height, self.width, |(x, y)| (y, x))
+    }
+
+    /// The grid turned a quarter clockwise, so the top row becomes the right
+    /// column.
+    pub fn rotated_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.remap(height, self.width, |(x, y)| (y, height - 1 - x))
+    }
+
+    /// The grid turned a quarter counterclockwise, so the top row becomes the
+    /// left column.
+    pub fn rotated_counterclockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.remap(self.height, width, |(x, y)| (width - 1 - y, x))
+    }
+
+    // Builds a grid of the given size, taking each cell from the position
+    // `source` maps it to in this grid.
+    fn remap(&self, width: usize, height: usize, source: impl Fn(Pos) -> Pos) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|pos| self[source(pos)].clone())
+            .collect();
+
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): Pos) -> &T {
+        assert!(
+            self.contains((x, y)),
+            "({}, {}) is outside a {}x{} grid",
+            x,
+            y,
+            self.width,
+            self.height
+        );
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, (x, y): Pos) -> &mut T {
+        assert!(
+            self.contains((x, y)),
+            "({}, {}) is outside a {}x{} grid",
+            x,
+            y,
+            self.width,
+            self.height
+        );
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+// Prints the cells of each row next to each other, one row per line, which
+// gives back the puzzle input for a grid of `char`s.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "#..\n.S#\n";
+
+    fn grid() -> Grid<char> {
+        Grid::parse(INPUT, char::from).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = grid();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], '#');
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.find(&'S'), Some((1, 1)));
+        assert_eq!(grid.to_string(), INPUT);
+        assert_eq!(
+            Grid::parse("#.\r\n.#\r\n", char::from).unwrap().to_string(),
+            "#.\n.#\n"
+        );
+        assert_eq!(
+            Grid::parse("#.\n.#\n\n\r\n", char::from)
+                .unwrap()
+                .to_string(),
+            "#.\n.#\n"
+        );
+        assert_eq!(
+            Grid::parse("..\n.\n", char::from),
+            Err(RaggedRow {
+                row: 1,
+                width: 1,
+                expected: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = grid();
+
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            [(1, 0), (0, 1)]
+        );
+        assert_eq!(
+            grid.neighbours8((2, 1)).collect::<Vec<_>>(),
+            [(1, 0), (2, 0), (1, 1)]
+        );
+        assert_eq!(grid.offset((1, 1), (1, -1)), Some((2, 0)));
+        assert_eq!(grid.offset((1, 1), (0, 1)), None);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = grid();
+
+        assert_eq!(grid.row(1), ['.', 'S', '#']);
+        assert_eq!(
+            grid.columns()
+                .map(|column| column.collect::<String>())
+                .collect::<Vec<_>>(),
+            ["#.", ".S", ".#"]
+        );
+        assert_eq!(grid.iter().nth(4), Some(((1, 1), &'S')));
+    }
+
+    #[test]
+    fn test_transform() {
+        let grid = grid();
+
+        assert_eq!(grid.transposed().to_string(), "#.\n.S\n.#\n");
+        assert_eq!(grid.rotated_clockwise().to_string(), ".#\nS.\n#.\n");
+        assert_eq!(grid.rotated_counterclockwise().to_string(), ".#\n.S\n#.\n");
+        assert_eq!(grid.rotated_clockwise().rotated_counterclockwise(), grid);
+        assert_eq!(grid.map(|&c| c == '#').find(&true), Some((0, 0)));
+    }
+}
@@ -0,0 +1,148 @@
+use std::{
+    ffi::OsString,
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use crate::{vault, AocError, Year};
+
+/// Overrides the directory puzzle inputs are read from, e.g. to switch
+/// accounts. It holds a directory per year, such as `2023/NN.txt`.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Where a day reads its puzzle input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// `NN.txt` in the inputs directory of the year, see [`Year::inputs_dir`].
+    Default,
+    /// A file given with `--input <path>`, which has to exist.
+    File(PathBuf),
+    /// Standard input, selected with `-` or `--input -`.
+    Stdin,
+}
+
+impl InputSource {
+    /// The source named by the value of `--input`, where `-` means stdin.
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => InputSource::Stdin,
+            path => InputSource::File(PathBuf::from(path)),
+        }
+    }
+
+    pub fn read(&self, year: &Year, day: u8) -> Result<String, AocError> {
+        match self {
+            InputSource::Default => read_input(year.input_path(day)),
+            // Unlike a default input that hasn't been downloaded, a file
+            // that was asked for by name is an error when it isn't there.
+            InputSource::File(path) => fs::read_to_string(path).map_err(|source| AocError::Io {
+                path: path.clone(),
+                source,
+            }),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(AocError::Stdin)?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+pub(crate) fn inputs_dir_from(year_dir: &Path, year: u16, var: Option<OsString>) -> PathBuf {
+    match var {
+        Some(dir) if !dir.is_empty() => Path::new(&dir).join(year.to_string()),
+        _ => year_dir.join("src/inputs"),
+    }
+}
+
+// Falls back to the encrypted input when there is no plain text.
+pub(crate) fn read_input(path: PathBuf) -> Result<String, AocError> {
+    match fs::read_to_string(&path) {
+        Ok(input) => Ok(input),
+        Err(source) if source.kind() == io::ErrorKind::NotFound => vault::read_encrypted(&path)
+            .unwrap_or_else(|| {
+                Err(AocError::MissingInput {
+                    day: day_of(&path),
+                    path,
+                })
+            }),
+        Err(source) => Err(AocError::Io { path, source }),
+    }
+}
+
+// Input files are named after their day, e.g. `07.txt` or `10_two_a.txt`.
+fn day_of(path: &Path) -> Option<u8> {
+    path.file_name()?.to_str()?.get(..2)?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const YEAR: Year = Year {
+        number: 2023,
+        dir: env!("CARGO_MANIFEST_DIR"),
+        days: &[],
+    };
+
+    #[test]
+    fn test_missing_input() {
+        let err = YEAR.read_example("99_one.txt").unwrap_err();
+        assert!(matches!(err, AocError::MissingInput { day: Some(99), .. }));
+
+        let dir = Path::new(YEAR.dir).join("src/test_inputs");
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "missing input for day 99: {} does not exist (expected 99_one.txt in {})",
+                dir.join("99_one.txt").display(),
+                dir.display()
+            )
+        );
+    }
+
+    #[test]
+    fn test_inputs_dir() {
+        let year_dir = Path::new(YEAR.dir);
+
+        assert_eq!(
+            inputs_dir_from(year_dir, 2023, None),
+            year_dir.join("src/inputs")
+        );
+        assert_eq!(
+            inputs_dir_from(year_dir, 2023, Some("".into())),
+            year_dir.join("src/inputs")
+        );
+        assert_eq!(
+            inputs_dir_from(year_dir, 2023, Some("/tmp/other".into())),
+            PathBuf::from("/tmp/other/2023")
+        );
+    }
+
+    #[test]
+    fn test_input_source_from_arg() {
+        assert_eq!(InputSource::from_arg("-"), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from_arg("a.txt"),
+            InputSource::File(PathBuf::from("a.txt"))
+        );
+    }
+
+    #[test]
+    fn test_read_file_source() {
+        let path = Path::new(YEAR.dir).join("Cargo.toml");
+        let source = InputSource::File(path.clone());
+
+        assert_eq!(
+            source.read(&YEAR, 0).unwrap(),
+            fs::read_to_string(path).unwrap()
+        );
+
+        let missing = InputSource::File(PathBuf::from("/nonexistent/01.txt"));
+        let err = missing.read(&YEAR, 1).unwrap_err();
+        assert!(matches!(err, AocError::Io { .. }), "{}", err);
+    }
+}
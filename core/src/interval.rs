@@ -0,0 +1,400 @@
+use std::{cmp::Ordering, fmt};
+
+/// The integers from `start` up to but not including `end`. An interval with
+/// `end <= start` is empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    /// The half-open interval `start..end`.
+    pub const fn new(start: i64, end: i64) -> Self {
+        Interval { start, end }
+    }
+
+    /// The closed interval `first..=last`.
+    pub const fn closed(first: i64, last: i64) -> Self {
+        Interval {
+            start: first,
+            end: last + 1,
+        }
+    }
+
+    /// The interval of `len` integers from `start`, as puzzles often give
+    /// ranges.
+    pub const fn with_len(start: i64, len: i64) -> Self {
+        Interval {
+            start,
+            end: start + len,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    /// How many integers the interval holds.
+    pub fn len(&self) -> i64 {
+        (self.end - self.start).max(0)
+    }
+
+    /// The last integer of the interval, if it isn't empty.
+    pub fn last(&self) -> Option<i64> {
+        (!self.is_empty()).then_some(self.end - 1)
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    pub fn overlaps(&self, other: &Interval) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    /// The integers in both intervals, which may be empty.
+    pub fn intersection(&self, other: &Interval) -> Interval {
+        Interval::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// Splits the interval into the integers below `at` and the ones from
+    /// `at` on, either of which may be empty.
+    pub fn split_at(&self, at: i64) -> (Interval, Interval) {
+        let at = at.clamp(self.start, self.end.max(self.start));
+        (
+            Interval::new(self.start, at),
+            Interval::new(at, self.end.max(at)),
+        )
+    }
+
+    /// The interval moved by `delta`.
+    pub fn shift(&self, delta: i64) -> Interval {
+        Interval::new(self.start + delta, self.end + delta)
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}..{}", self.start, self.end)
+    }
+}
+
+/// A set of integers, kept as sorted intervals that neither overlap nor
+/// touch.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        IntervalSet::default()
+    }
+
+    /// Adds the integers of `interval` to the set.
+    pub fn insert(&mut self, interval: Interval) {
+        if interval.is_empty() {
+            return;
+        }
+
+        // Every interval that overlaps or touches the new one is merged into
+        // it, and they are all contiguous in the sorted list.
+        let from = self.intervals.partition_point(|i| i.end < interval.start);
+        let to = self.intervals.partition_point(|i| i.start <= interval.end);
+        let merged = self.intervals[from..to].iter().fold(interval, |merged, i| {
+            Interval::new(merged.start.min(i.start), merged.end.max(i.end))
+        });
+
+        self.intervals.splice(from..to, [merged]);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// How many integers the set holds.
+    pub fn len(&self) -> i64 {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+
+    /// The smallest integer in the set.
+    pub fn min(&self) -> Option<i64> {
+        self.intervals.first().map(|i| i.start)
+    }
+
+    /// The largest integer in the set.
+    pub fn max(&self) -> Option<i64> {
+        self.intervals.last().and_then(Interval::last)
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.intervals
+            .binary_search_by(|i| {
+                if i.end <= value {
+                    Ordering::Less
+                } else if i.start > value {
+                    Ordering::Greater
+                } else {
+                    Ordering::Equal
+                }
+            })
+            .is_ok()
+    }
+
+    /// The intervals of the set, in order.
+    pub fn iter(&self) -> impl Iterator<Item = &Interval> {
+        self.intervals.iter()
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        let mut union = self.clone();
+        union.extend(other.iter().copied());
+        union
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let (mut a, mut b) = (self.iter().peekable(), other.iter().peekable());
+        let mut intersection = IntervalSet::new();
+
+        while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+            intersection.insert(x.intersection(y));
+            if x.end < y.end {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+
+        intersection
+    }
+
+    /// The integers of this set that aren't in `other`.
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut difference = IntervalSet::new();
+
+        for interval in self.iter() {
+            let mut rest = *interval;
+            for hole in other.iter().filter(|hole| hole.overlaps(interval)) {
+                let (before, _) = rest.split_at(hole.start);
+                difference.insert(before);
+                rest = rest.split_at(hole.end).1;
+            }
+            difference.insert(rest);
+        }
+
+        difference
+    }
+
+    /// Splits the set into the integers below `at` and the ones from `at` on.
+    pub fn split_at(&self, at: i64) -> (IntervalSet, IntervalSet) {
+        let (mut below, mut above) = (IntervalSet::new(), IntervalSet::new());
+
+        for interval in self.iter() {
+            let (low, high) = interval.split_at(at);
+            below.insert(low);
+            above.insert(high);
+        }
+
+        (below, above)
+    }
+
+    /// The set with every integer moved by `delta`.
+    pub fn shift(&self, delta: i64) -> IntervalSet {
+        IntervalSet {
+            intervals: self.iter().map(|i| i.shift(delta)).collect(),
+        }
+    }
+}
+
+impl Extend<Interval> for IntervalSet {
+    fn extend<I: IntoIterator<Item = Interval>>(&mut self, intervals: I) {
+        for interval in intervals {
+            self.insert(interval);
+        }
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(intervals: I) -> Self {
+        let mut set = IntervalSet::new();
+        set.extend(intervals);
+        set
+    }
+}
+
+impl From<Interval> for IntervalSet {
+    fn from(interval: Interval) -> Self {
+        IntervalSet::from_iter([interval])
+    }
+}
+
+/// A piecewise-linear map of integers: each source interval is shifted by its
+/// own offset, and everything outside of them maps to itself.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RangeMap {
+    // Sorted by start, without overlaps.
+    pieces: Vec<(Interval, i64)>,
+}
+
+impl RangeMap {
+    pub fn new() -> Self {
+        RangeMap::default()
+    }
+
+    /// Maps `source` to `source` moved by `offset`. Returns `false`, and
+    /// leaves the map as it was, if `source` overlaps an interval that is
+    /// already mapped.
+    pub fn insert(&mut self, source: Interval, offset: i64) -> bool {
+        if source.is_empty() {
+            return true;
+        }
+
+        let at = self.pieces.partition_point(|(i, _)| i.start < source.start);
+        let overlaps = |neighbour: Option<&(Interval, i64)>| {
+            neighbour.is_some_and(|(i, _)| i.overlaps(&source))
+        };
+        if overlaps(at.checked_sub(1).and_then(|at| self.pieces.get(at)))
+            || overlaps(self.pieces.get(at))
+        {
+            return false;
+        }
+
+        self.pieces.insert(at, (source, offset));
+        true
+    }
+
+    /// Where the map sends a single integer.
+    pub fn map(&self, value: i64) -> i64 {
+        let at = self.pieces.partition_point(|(i, _)| i.start <= value);
+        match at.checked_sub(1).map(|at| self.pieces[at]) {
+            Some((source, offset)) if source.contains(value) => value + offset,
+            _ => value,
+        }
+    }
+
+    /// Where the map sends every integer of `set`.
+    pub fn map_set(&self, set: &IntervalSet) -> IntervalSet {
+        let mut mapped = IntervalSet::new();
+        let mut unmapped = set.clone();
+
+        for &(source, offset) in &self.pieces {
+            let inside = unmapped.intersection(&source.into());
+            if inside.is_empty() {
+                continue;
+            }
+            mapped.extend(inside.shift(offset).iter().copied());
+            unmapped = unmapped.difference(&inside);
+        }
+
+        mapped.union(&unmapped)
+    }
+}
+
+impl FromIterator<(Interval, i64)> for RangeMap {
+    /// Builds a map from `(source, offset)` pairs.
+    ///
+    /// # Panics
+    ///
+    /// If two of the sources overlap.
+    fn from_iter<I: IntoIterator<Item = (Interval, i64)>>(pieces: I) -> Self {
+        let mut map = RangeMap::new();
+        for (source, offset) in pieces {
+            assert!(
+                map.insert(source, offset),
+                "{} overlaps another mapped interval",
+                source
+            );
+        }
+        map
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(intervals: &[(i64, i64)]) -> IntervalSet {
+        intervals
+            .iter()
+            .map(|&(a, b)| Interval::new(a, b))
+            .collect()
+    }
+
+    #[test]
+    fn test_interval() {
+        let interval = Interval::closed(1, 4000);
+
+        assert_eq!(interval, Interval::new(1, 4001));
+        assert_eq!(interval.len(), 4000);
+        assert_eq!(interval.last(), Some(4000));
+        assert_eq!(Interval::with_len(79, 14), Interval::new(79, 93));
+        assert!(Interval::new(5, 5).is_empty());
+        assert_eq!(Interval::new(5, 2).len(), 0);
+
+        assert_eq!(
+            interval.split_at(1351),
+            (Interval::new(1, 1351), Interval::new(1351, 4001))
+        );
+        assert!(interval.split_at(0).0.is_empty());
+        assert!(interval.split_at(5000).1.is_empty());
+        assert_eq!(
+            interval.intersection(&Interval::new(-5, 10)),
+            Interval::new(1, 10)
+        );
+        assert!(!interval.overlaps(&Interval::new(4001, 4005)));
+        assert_eq!(interval.shift(-1), Interval::new(0, 4000));
+    }
+
+    #[test]
+    fn test_insert() {
+        let set = set(&[(10, 20), (0, 5), (30, 40), (5, 7), (18, 31), (50, 50)]);
+
+        assert_eq!(set, self::set(&[(0, 7), (10, 40)]));
+        assert_eq!(set.len(), 37);
+        assert_eq!((set.min(), set.max()), (Some(0), Some(39)));
+        assert!(set.contains(6) && set.contains(10) && !set.contains(7));
+        assert!(IntervalSet::new().max().is_none());
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = set(&[(0, 10), (20, 30)]);
+        let b = set(&[(5, 25), (28, 40)]);
+
+        assert_eq!(a.union(&b), set(&[(0, 40)]));
+        assert_eq!(a.intersection(&b), set(&[(5, 10), (20, 25), (28, 30)]));
+        assert_eq!(a.difference(&b), set(&[(0, 5), (25, 28)]));
+        assert_eq!(b.difference(&a), set(&[(10, 20), (30, 40)]));
+        assert_eq!(
+            a.split_at(25),
+            (set(&[(0, 10), (20, 25)]), set(&[(25, 30)]))
+        );
+        assert_eq!(a.shift(3), set(&[(3, 13), (23, 33)]));
+    }
+
+    #[test]
+    fn test_range_map() {
+        // The seed-to-soil map of 2023 day 05.
+        let map: RangeMap = [
+            (Interval::with_len(98, 2), 50 - 98),
+            (Interval::with_len(50, 48), 52 - 50),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(
+            [79, 14, 55, 13, 98, 99, 100].map(|v| map.map(v)),
+            [81, 14, 57, 13, 50, 51, 100]
+        );
+        assert_eq!(
+            map.map_set(&set(&[(45, 55), (97, 102)])),
+            set(&[(45, 57), (99, 102)])
+        );
+
+        let mut map = map;
+        assert!(!map.insert(Interval::new(90, 99), 1));
+        assert!(map.insert(Interval::new(0, 50), 1000));
+        assert_eq!(map.map(0), 1000);
+    }
+}
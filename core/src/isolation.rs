@@ -0,0 +1,273 @@
+use std::{
+    any::Any,
+    cell::Cell,
+    collections::BTreeMap,
+    fmt,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        mpsc::{self, RecvTimeoutError},
+        Arc, Mutex, MutexGuard, Once,
+    },
+    thread,
+    time::Duration,
+};
+
+use rayon::ThreadPoolBuilder;
+
+use crate::{
+    runner::{Outcome, Report},
+    Day, Part,
+};
+
+// Some days recurse deeply, more than the 2 MiB a spawned thread gets by
+// default allows.
+const STACK_SIZE: usize = 64 * 1024 * 1024;
+
+// Every part is solved with a number of its own, which its threads are
+// tagged with, 0 for the threads of no part.
+thread_local! {
+    static RUN: Cell<u64> = const { Cell::new(0) };
+}
+
+static HOOK: Once = Once::new();
+static NEXT_RUN: AtomicU64 = AtomicU64::new(1);
+
+// Where the last panic on a thread of each part happened. The payload is
+// passed on from a rayon worker to the part without running the hook again,
+// so the location is kept until the part picks it up.
+static LOCATIONS: Mutex<BTreeMap<u64, String>> = Mutex::new(BTreeMap::new());
+
+/// The message and location of a panic in a solution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Panic {
+    pub message: String,
+    pub location: Option<String>,
+}
+
+impl fmt::Display for Panic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "panicked at {}: {}", location, self.message),
+            None => write!(f, "panicked: {}", self.message),
+        }
+    }
+}
+
+/// Solves each wanted part of a day on its own threads, so a part that panics
+/// or runs past `timeout` is reported as such instead of taking down the
+/// runner. Each part parses the input itself and the report keeps the fastest
+/// parse.
+///
+/// A part runs in a rayon thread pool of its own, so a panic on one of its
+/// workers is reported with its location too. A panic on a thread the part
+/// spawns some other way is reported without one.
+///
+/// A thread that timed out can't be stopped, it keeps running in the
+/// background until the process exits.
+pub fn solve_isolated(
+    day: &Day,
+    input: &str,
+    part: Option<Part>,
+    timeout: Option<Duration>,
+) -> Report {
+    install_hook();
+    let input: Arc<str> = input.into();
+    let mut report = Report {
+        day: day.number,
+        parse: Duration::ZERO,
+        part_one: None,
+        part_two: None,
+    };
+    let mut parse: Option<Duration> = None;
+
+    for p in Part::ALL {
+        if part.is_some_and(|part| part != p) {
+            continue;
+        }
+
+        let (parsed, outcome) = solve_part(day, &input, p, timeout);
+        if let Some(parsed) = parsed {
+            parse = Some(parse.map_or(parsed, |parse| parse.min(parsed)));
+        }
+        *report.part_mut(p) = Some(outcome);
+    }
+    report.parse = parse.unwrap_or_default();
+
+    report
+}
+
+// Returns how long parsing took, if the part got that far, and how the part
+// ended.
+fn solve_part(
+    day: &Day,
+    input: &Arc<str>,
+    part: Part,
+    timeout: Option<Duration>,
+) -> (Option<Duration>, Outcome) {
+    let (sender, receiver) = mpsc::channel();
+    let (solve, input) = (day.solve, Arc::clone(input));
+    let name = format!("day {:02} {}", day.number, part);
+    let run = NEXT_RUN.fetch_add(1, Ordering::Relaxed);
+
+    thread::Builder::new()
+        .name(name.clone())
+        .stack_size(STACK_SIZE)
+        .spawn(move || {
+            RUN.set(run);
+            let pool = ThreadPoolBuilder::new()
+                .thread_name(move |i| format!("{} worker {}", name, i))
+                .stack_size(STACK_SIZE)
+                .start_handler(move |_| RUN.set(run))
+                .build()
+                .expect("failed to start the threads to solve the day");
+
+            let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                pool.install(|| solve(&input, Some(part)))
+            }));
+            // The receiver is gone if the part timed out.
+            let _ = sender.send(result.map_err(|payload| Panic {
+                message: message_of(&*payload),
+                location: locations().remove(&run),
+            }));
+        })
+        .expect("failed to spawn a thread to solve the day");
+
+    let received = match timeout {
+        Some(timeout) => receiver.recv_timeout(timeout),
+        None => receiver.recv().map_err(RecvTimeoutError::from),
+    };
+    let received = match received {
+        Err(RecvTimeoutError::Timeout) => return (None, Outcome::TimedOut(timeout.unwrap())),
+        received => received.ok(),
+    };
+
+    match received {
+        Some(Ok(mut report)) => {
+            let outcome = report.part_mut(part).take();
+            (Some(report.parse), outcome.expect("the part was asked for"))
+        }
+        Some(Err(panic)) => (None, Outcome::Panicked(panic)),
+        None => unreachable!("the thread always sends a result before it exits"),
+    }
+}
+
+// Keeps the default hook from printing panics on the threads of a part,
+// which are caught and reported, and records their location, which the
+// payload doesn't carry. Panics on any other thread go to the previous hook.
+fn install_hook() {
+    HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| match (RUN.get(), info.location()) {
+            (0, _) => previous(info),
+            (run, Some(location)) => {
+                locations().insert(run, location.to_string());
+            }
+            (_, None) => {}
+        }));
+    });
+}
+
+// A panicking hook would abort, so a poisoned lock is used as it is.
+fn locations() -> MutexGuard<'static, BTreeMap<u64, String>> {
+    LOCATIONS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+// `panic!` with a literal carries a `&str`, and with format arguments a
+// `String`. Anything else came from `panic_any`.
+fn message_of(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "Box<dyn Any>".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Answer, Solution};
+
+    struct Faulty;
+
+    impl Solution for Faulty {
+        const DAY: u8 = 21;
+        type Input<'a> = Vec<u64>;
+
+        fn parse(input: &str) -> Self::Input<'_> {
+            input.lines().map(|line| line.parse().unwrap()).collect()
+        }
+
+        fn part_one(input: &Self::Input<'_>) -> Answer {
+            input.iter().sum::<u64>().into()
+        }
+
+        fn part_two(input: &Self::Input<'_>) -> Answer {
+            if input.len() > 2 {
+                thread::sleep(Duration::from_secs(60));
+            }
+            panic!("no solution for {} numbers", input.len())
+        }
+    }
+
+    #[test]
+    fn test_panicked() {
+        let day = Day::new::<Faulty>();
+        let report = solve_isolated(&day, "1\n2", None, None);
+
+        assert_eq!(report.part_one.unwrap().answer(), Some(&3.into()));
+        let Some(Outcome::Panicked(panic)) = report.part_two else {
+            panic!("expected part two to panic");
+        };
+        assert_eq!(panic.message, "no solution for 2 numbers");
+        assert!(panic.location.unwrap().contains("isolation.rs"));
+
+        let report = solve_isolated(&day, "1\nx", None, None);
+        assert!(matches!(report.part_one, Some(Outcome::Panicked(_))));
+        assert_eq!(report.parse, Duration::ZERO);
+    }
+
+    struct Parallel;
+
+    impl Solution for Parallel {
+        const DAY: u8 = 16;
+        type Input<'a> = ();
+
+        fn parse(_input: &str) {}
+
+        fn part_one(_input: &()) -> Answer {
+            let (beams, ()) = rayon::join(|| 0, || panic!("no beam"));
+            beams.into()
+        }
+
+        fn part_two(_input: &()) -> Answer {
+            0.into()
+        }
+    }
+
+    #[test]
+    fn test_panicked_on_worker() {
+        let day = Day::new::<Parallel>();
+        let report = solve_isolated(&day, "", Some(Part::One), None);
+
+        let Some(Outcome::Panicked(panic)) = report.part_one else {
+            panic!("expected part one to panic");
+        };
+        assert_eq!(panic.message, "no beam");
+        assert!(panic.location.unwrap().contains("isolation.rs"));
+    }
+
+    #[test]
+    fn test_timed_out() {
+        let day = Day::new::<Faulty>();
+        let timeout = Duration::from_millis(50);
+        let report = solve_isolated(&day, "1\n2\n3", Some(Part::Two), Some(timeout));
+
+        assert!(report.part_one.is_none());
+        assert!(matches!(report.part_two, Some(Outcome::TimedOut(t)) if t == timeout));
+    }
+}
@@ -0,0 +1,26 @@
+mod answer;
+pub mod answers;
+pub mod cycle;
+mod error;
+pub mod examples;
+pub mod geometry;
+pub mod graph;
+pub mod grid;
+mod input;
+pub mod interval;
+pub mod isolation;
+pub mod math;
+pub mod runner;
+pub mod search;
+mod solution;
+pub mod vault;
+mod year;
+
+pub use answer::Answer;
+pub use answers::assert_known_answers;
+pub use error::AocError;
+pub use examples::{assert_example, Params};
+pub use grid::Grid;
+pub use input::{InputSource, INPUT_DIR_VAR};
+pub use solution::{Day, Part, Solution};
+pub use year::Year;
@@ -0,0 +1,191 @@
+//! Number theory for puzzles whose answer is where several cycles line up,
+//! and exact integer square roots for the quadratics of others.
+
+/// The greatest common divisor, where `gcd(0, 0)` is 0.
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b > 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+/// The least common multiple, where the multiple of anything and 0 is 0.
+///
+/// # Panics
+///
+/// If the multiple doesn't fit in a `u64`.
+pub fn lcm(a: u64, b: u64) -> u64 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+
+    (a / gcd(a, b))
+        .checked_mul(b)
+        .unwrap_or_else(|| panic!("the lcm of {} and {} overflows u64", a, b))
+}
+
+/// The greatest common divisor of all the numbers, 0 for none.
+pub fn gcd_all(numbers: impl IntoIterator<Item = u64>) -> u64 {
+    numbers.into_iter().fold(0, gcd)
+}
+
+/// The least common multiple of all the numbers, 1 for none.
+///
+/// # Panics
+///
+/// If the multiple doesn't fit in a `u64`.
+pub fn lcm_all(numbers: impl IntoIterator<Item = u64>) -> u64 {
+    numbers.into_iter().fold(1, lcm)
+}
+
+/// The integer square root, the largest `r` with `r * r <= n`.
+pub fn isqrt(n: u64) -> u64 {
+    isqrt_u128(n.into()) as u64
+}
+
+/// The integer square root, the largest `r` with `r * r <= n`.
+pub fn isqrt_u128(n: u128) -> u128 {
+    if n < 2 {
+        return n;
+    }
+
+    // Newton's method from above, starting at a power of two no smaller
+    // than the root, stops at the root rounded down.
+    let mut root = 1 << (128 - n.leading_zeros()).div_ceil(2);
+    loop {
+        let next = (root + n / root) / 2;
+        if next >= root {
+            return root;
+        }
+        root = next;
+    }
+}
+
+/// Returns `(g, x, y)` where `g` is the non-negative greatest common divisor
+/// of `a` and `b`, and `a * x + b * y == g`.
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut r0, mut r1) = (a as i128, b as i128);
+    let (mut x0, mut x1) = (1_i128, 0_i128);
+    let (mut y0, mut y1) = (0_i128, 1_i128);
+
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (x0, x1) = (x1, x0 - q * x1);
+        (y0, y1) = (y1, y0 - q * y1);
+    }
+
+    if r0 < 0 {
+        (r0, x0, y0) = (-r0, -x0, -y0);
+    }
+    (r0 as i64, x0 as i64, y0 as i64)
+}
+
+/// The `x` in `0..modulus` with `a * x ≡ 1 (mod modulus)`, if `a` and
+/// `modulus` are coprime.
+pub fn mod_inverse(a: i64, modulus: i64) -> Option<i64> {
+    assert!(modulus > 0, "modulus {} is not positive", modulus);
+    let (g, x, _) = extended_gcd(a.rem_euclid(modulus), modulus);
+
+    (g == 1).then(|| x.rem_euclid(modulus))
+}
+
+/// Solves a system of congruences `x ≡ residue (mod modulus)`, given as
+/// `(residue, modulus)` pairs, with the Chinese remainder theorem. The moduli
+/// don't have to be coprime.
+///
+/// Returns the smallest non-negative `x` and the modulus of the solution,
+/// which is the least common multiple of the moduli, or `None` if the
+/// congruences contradict each other or the modulus overflows an `i64`.
+pub fn crt(congruences: impl IntoIterator<Item = (i64, i64)>) -> Option<(i64, i64)> {
+    congruences
+        .into_iter()
+        .try_fold((0, 1), |(x, m), (residue, modulus)| {
+            assert!(modulus > 0, "modulus {} is not positive", modulus);
+            let residue = residue.rem_euclid(modulus);
+
+            // x + m * k ≡ residue (mod modulus), solved for k.
+            let (g, inverse, _) = extended_gcd(m, modulus);
+            let diff = residue as i128 - x as i128;
+            if diff % g as i128 != 0 {
+                return None;
+            }
+            let step = (modulus / g) as i128;
+            let k = (diff / g as i128 * inverse as i128).rem_euclid(step);
+
+            let combined = i64::try_from(m as i128 * step).ok()?;
+            let x = (x as i128 + m as i128 * k).rem_euclid(combined as i128);
+            Some((x as i64, combined))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(48, 18), 6);
+        assert_eq!(gcd(0, 7), 7);
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(0, 6), 0);
+        assert_eq!(gcd_all([12, 18, 30]), 6);
+        assert_eq!(gcd_all([]), 0);
+        assert_eq!(lcm_all([2, 3, 4]), 12);
+        assert_eq!(lcm_all([]), 1);
+        // Dividing first keeps cycle lengths like day 08's from overflowing.
+        assert_eq!(lcm(u64::MAX / 2, u64::MAX / 2), u64::MAX / 2);
+
+        let overflow = std::panic::catch_unwind(|| lcm(u64::MAX, u64::MAX - 1));
+        assert!(overflow.is_err());
+    }
+
+    #[test]
+    fn test_isqrt() {
+        for n in [0_u64, 1, 2, 3, 4, 99, 100, 1 << 31, 4_294_967_295] {
+            let square = n * n;
+            assert_eq!(isqrt(square), n);
+            if n > 0 {
+                assert_eq!(isqrt(square - 1), n - 1);
+            }
+        }
+        assert_eq!(isqrt(u64::MAX), u64::from(u32::MAX));
+
+        let root = u128::from(u64::MAX);
+        assert_eq!(isqrt_u128(root * root), root);
+        assert_eq!(isqrt_u128(root * root - 1), root - 1);
+        assert_eq!(isqrt_u128(u128::MAX), root);
+        assert_eq!(isqrt_u128(1 << 100), 1 << 50);
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        for (a, b) in [(240, 46), (-240, 46), (240, -46), (0, 5), (7, 0), (17, 5)] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(g, gcd(a.unsigned_abs(), b.unsigned_abs()) as i64);
+            assert_eq!(a * x + b * y, g, "{} {}", a, b);
+        }
+    }
+
+    #[test]
+    fn test_mod_inverse() {
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(10, 17), Some(12));
+        assert_eq!(mod_inverse(6, 9), None);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt([(-1, 4), (5, 6)]), Some((11, 12)));
+        assert_eq!(crt([(1, 4), (2, 6)]), None);
+        assert_eq!(crt([]), Some((0, 1)));
+        assert_eq!(
+            crt([(0, 1_000_000_007), (1, 998_244_353)]),
+            Some((993_328_913_953_302_349, 998_244_359_987_710_471))
+        );
+        assert_eq!(crt([(0, i64::MAX), (0, i64::MAX - 1)]), None);
+    }
+}
@@ -0,0 +1,486 @@
+use std::{
+    fmt::{self, Write as _},
+    time::{Duration, Instant},
+};
+
+use serde::Serialize;
+
+use crate::{isolation::Panic, Answer, AocError, Part};
+
+/// A value together with the wall-clock time it took to compute.
+#[derive(Debug, Clone)]
+pub struct Timed<T> {
+    pub value: T,
+    pub time: Duration,
+}
+
+/// Runs `f` and records how long it took.
+pub fn time<T>(f: impl FnOnce() -> T) -> Timed<T> {
+    let start = Instant::now();
+    let value = f();
+
+    Timed {
+        value,
+        time: start.elapsed(),
+    }
+}
+
+/// How one part of a day ended.
+#[derive(Debug, Clone)]
+pub enum Outcome {
+    Solved(Timed<Answer>),
+    Panicked(Panic),
+    /// The part was still running when the timeout ran out.
+    TimedOut(Duration),
+    MissingInput,
+    /// The input is there but couldn't be read, such as an encrypted input
+    /// without its key.
+    UnreadableInput(String),
+}
+
+impl Outcome {
+    pub fn answer(&self) -> Option<&Answer> {
+        match self {
+            Outcome::Solved(timed) => Some(&timed.value),
+            _ => None,
+        }
+    }
+
+    /// How long the part took, if it was solved.
+    pub fn time(&self) -> Option<Duration> {
+        match self {
+            Outcome::Solved(timed) => Some(timed.time),
+            _ => None,
+        }
+    }
+
+    pub fn status(&self) -> &'static str {
+        match self {
+            Outcome::Solved(_) => "solved",
+            Outcome::Panicked(_) => "panicked",
+            Outcome::TimedOut(_) => "timed out",
+            Outcome::MissingInput => "missing input",
+            Outcome::UnreadableInput(_) => "unreadable",
+        }
+    }
+}
+
+// The answer of a solved part, or what went wrong otherwise.
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Solved(timed) => write!(f, "{}", timed.value),
+            Outcome::Panicked(panic) => write!(f, "{}", panic),
+            Outcome::TimedOut(timeout) => write!(f, "timed out after {:.2?}", timeout),
+            Outcome::MissingInput => write!(f, "input missing"),
+            Outcome::UnreadableInput(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+/// The outcome of each part of a day and how long parsing took. A part is
+/// `None` when only the other one was asked for.
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub day: u8,
+    pub parse: Duration,
+    pub part_one: Option<Outcome>,
+    pub part_two: Option<Outcome>,
+}
+
+impl Report {
+    /// A report for a day whose input couldn't be found.
+    pub fn missing_input(day: u8, part: Option<Part>) -> Self {
+        Report::unsolved(day, part, Outcome::MissingInput)
+    }
+
+    /// A report for a day whose input couldn't be read.
+    pub fn unreadable_input(day: u8, part: Option<Part>, err: &AocError) -> Self {
+        Report::unsolved(day, part, Outcome::UnreadableInput(err.to_string()))
+    }
+
+    // A report with the same outcome for every part asked for.
+    fn unsolved(day: u8, part: Option<Part>, outcome: Outcome) -> Self {
+        let outcome = |p| part.is_none_or(|part| part == p).then(|| outcome.clone());
+
+        Report {
+            day,
+            parse: Duration::ZERO,
+            part_one: outcome(Part::One),
+            part_two: outcome(Part::Two),
+        }
+    }
+
+    pub fn part(&self, part: Part) -> Option<&Outcome> {
+        match part {
+            Part::One => self.part_one.as_ref(),
+            Part::Two => self.part_two.as_ref(),
+        }
+    }
+
+    pub(crate) fn part_mut(&mut self, part: Part) -> &mut Option<Outcome> {
+        match part {
+            Part::One => &mut self.part_one,
+            Part::Two => &mut self.part_two,
+        }
+    }
+
+    /// The parts that were run, in order.
+    pub fn parts(&self) -> impl Iterator<Item = (Part, &Outcome)> {
+        Part::ALL
+            .into_iter()
+            .filter_map(|part| Some((part, self.part(part)?)))
+    }
+
+    /// The time spent parsing and solving, leaving out parts that failed.
+    pub fn total(&self) -> Duration {
+        self.parse
+            + self
+                .parts()
+                .filter_map(|(_, outcome)| outcome.time())
+                .sum::<Duration>()
+    }
+}
+
+/// Solves a day `runs` times with `solve` and keeps the fastest time of each
+/// step, which is less sensitive to noise than a single run. A day with a part
+/// that failed isn't solved again.
+pub fn bench(runs: usize, mut solve: impl FnMut() -> Report) -> Report {
+    let mut best = solve();
+
+    for _ in 1..runs {
+        if best.parts().any(|(_, outcome)| outcome.answer().is_none()) {
+            break;
+        }
+        let report = solve();
+
+        best.parse = best.parse.min(report.parse);
+        for (best, outcome) in [
+            (&mut best.part_one, report.part_one),
+            (&mut best.part_two, report.part_two),
+        ] {
+            if let (Some(Outcome::Solved(best)), Some(Outcome::Solved(timed))) = (best, outcome) {
+                best.time = best.time.min(timed.time);
+            }
+        }
+    }
+
+    best
+}
+
+// One line of the JSON and CSV output. Times are in nanoseconds so the
+// files can be compared between runs without parsing units. The error of a
+// part that didn't solve is only written to JSON.
+#[derive(Serialize)]
+struct Row {
+    year: u16,
+    day: u8,
+    parse_ns: u64,
+    part_one: Option<String>,
+    part_one_status: Option<&'static str>,
+    part_one_ns: Option<u64>,
+    part_one_error: Option<String>,
+    part_two: Option<String>,
+    part_two_status: Option<&'static str>,
+    part_two_ns: Option<u64>,
+    part_two_error: Option<String>,
+    total_ns: u64,
+}
+
+impl From<&(u16, Report)> for Row {
+    fn from((year, report): &(u16, Report)) -> Self {
+        let answer = |part: Option<&Outcome>| Some(part?.answer()?.to_string());
+        let status = |part: Option<&Outcome>| part.map(Outcome::status);
+        let time = |part: Option<&Outcome>| part?.time().map(nanos);
+        let error = |part: Option<&Outcome>| match part? {
+            Outcome::Solved(_) => None,
+            outcome => Some(outcome.to_string()),
+        };
+        let (one, two) = (report.part_one.as_ref(), report.part_two.as_ref());
+
+        Row {
+            year: *year,
+            day: report.day,
+            parse_ns: nanos(report.parse),
+            part_one: answer(one),
+            part_one_status: status(one),
+            part_one_ns: time(one),
+            part_one_error: error(one),
+            part_two: answer(two),
+            part_two_status: status(two),
+            part_two_ns: time(two),
+            part_two_error: error(two),
+            total_ns: nanos(report.total()),
+        }
+    }
+}
+
+fn nanos(time: Duration) -> u64 {
+    time.as_nanos().try_into().unwrap_or(u64::MAX)
+}
+
+/// Formats the timings of every report, tagged with its year, as a table
+/// ending with the total time of the run and each day's share of it.
+pub fn summary(reports: &[(u16, Report)]) -> String {
+    let total: Duration = reports.iter().map(|(_, r)| r.total()).sum();
+    let mut table = String::new();
+
+    writeln!(
+        table,
+        "{:>5} {:>3} {:>10} {:>10} {:>10} {:>10} {:>6}",
+        "Year", "Day", "Parse", "Part one", "Part two", "Total", "%"
+    )
+    .unwrap();
+    for (year, report) in reports {
+        writeln!(
+            table,
+            "{:>5} {:>3} {:>10} {:>10} {:>10} {:>10} {:>6.1}",
+            year,
+            format!("{:02}", report.day),
+            format!("{:.2?}", report.parse),
+            part_time(report.part_one.as_ref()),
+            part_time(report.part_two.as_ref()),
+            format!("{:.2?}", report.total()),
+            share(report.total(), total)
+        )
+        .unwrap();
+    }
+    writeln!(
+        table,
+        "{:>9} {:>10} {:>10} {:>10} {:>10} {:>6.1}",
+        "Total",
+        format!("{:.2?}", sum(reports, |r| r.parse)),
+        format!("{:.2?}", sum(reports, |r| time_of(r.part_one.as_ref()))),
+        format!("{:.2?}", sum(reports, |r| time_of(r.part_two.as_ref()))),
+        format!("{:.2?}", total),
+        share(total, total)
+    )
+    .unwrap();
+
+    table
+}
+
+// The time of a solved part, or its status otherwise.
+fn part_time(part: Option<&Outcome>) -> String {
+    match part {
+        Some(Outcome::Solved(timed)) => format!("{:.2?}", timed.time),
+        Some(outcome) => outcome.status().to_string(),
+        None => "-".to_string(),
+    }
+}
+
+fn time_of(part: Option<&Outcome>) -> Duration {
+    part.and_then(Outcome::time).unwrap_or_default()
+}
+
+fn sum(reports: &[(u16, Report)], time: impl Fn(&Report) -> Duration) -> Duration {
+    reports.iter().map(|(_, report)| time(report)).sum()
+}
+
+fn share(time: Duration, total: Duration) -> f64 {
+    if total.is_zero() {
+        0.0
+    } else {
+        time.as_secs_f64() / total.as_secs_f64() * 100.0
+    }
+}
+
+/// The reports as a JSON array with one object per day.
+pub fn to_json(reports: &[(u16, Report)]) -> String {
+    let rows: Vec<Row> = reports.iter().map(Row::from).collect();
+
+    serde_json::to_string_pretty(&rows).unwrap()
+}
+
+/// The reports as CSV with a header line and one line per day.
+pub fn to_csv(reports: &[(u16, Report)]) -> String {
+    let mut csv =
+        String::from("year,day,parse_ns,part_one,part_one_status,part_one_ns,part_two,part_two_status,part_two_ns,total_ns\n");
+
+    for row in reports.iter().map(Row::from) {
+        writeln!(
+            csv,
+            "{},{},{},{},{},{},{},{},{},{}",
+            row.year,
+            row.day,
+            row.parse_ns,
+            csv_field(row.part_one.as_deref().unwrap_or_default()),
+            row.part_one_status.unwrap_or_default(),
+            optional(row.part_one_ns),
+            csv_field(row.part_two.as_deref().unwrap_or_default()),
+            row.part_two_status.unwrap_or_default(),
+            optional(row.part_two_ns),
+            row.total_ns
+        )
+        .unwrap();
+    }
+
+    csv
+}
+
+// Parts that weren't solved are left empty.
+fn optional(nanos: Option<u64>) -> String {
+    nanos.map(|nanos| nanos.to_string()).unwrap_or_default()
+}
+
+// Text answers may contain commas, quotes or line breaks, so those fields
+// are quoted.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Day, Solution};
+
+    fn report(day: u8, part_one: Answer, part_two: Answer) -> (u16, Report) {
+        let report = Report {
+            day,
+            parse: Duration::from_micros(5),
+            part_one: Some(Outcome::Solved(Timed {
+                value: part_one,
+                time: Duration::from_micros(10),
+            })),
+            part_two: Some(Outcome::Solved(Timed {
+                value: part_two,
+                time: Duration::from_micros(25),
+            })),
+        };
+
+        (2023, report)
+    }
+
+    #[test]
+    fn test_summary() {
+        let reports = [report(1, 1.into(), 2.into()), report(2, 3.into(), 4.into())];
+        let table = summary(&reports);
+        let lines: Vec<&str> = table.lines().collect();
+
+        assert_eq!(lines.len(), 4);
+        assert!(lines[1].trim_start().starts_with("2023  01"));
+        assert!(lines[1].contains("40.00µs"));
+        assert!(lines[1].ends_with("50.0"));
+        assert!(lines[3].trim_start().starts_with("Total"));
+        assert!(lines[3].contains("80.00µs"));
+        assert!(lines[3].ends_with("100.0"));
+    }
+
+    #[test]
+    fn test_to_csv() {
+        let reports = [report(7, 6440.into(), "A,\"B\"".into())];
+
+        assert_eq!(
+            to_csv(&reports),
+            "year,day,parse_ns,part_one,part_one_status,part_one_ns,part_two,part_two_status,part_two_ns,total_ns\n\
+             2023,7,5000,6440,solved,10000,\"A,\"\"B\"\"\",solved,25000,40000\n"
+        );
+    }
+
+    #[test]
+    fn test_to_json() {
+        let reports = [report(7, 6440.into(), "AB".into())];
+        let json: serde_json::Value = serde_json::from_str(&to_json(&reports)).unwrap();
+
+        assert_eq!(json[0]["year"], 2023);
+        assert_eq!(json[0]["day"], 7);
+        assert_eq!(json[0]["part_one"], "6440");
+        assert_eq!(json[0]["part_two"], "AB");
+        assert_eq!(json[0]["total_ns"], 40000);
+    }
+
+    #[test]
+    fn test_single_part() {
+        let (year, mut report) = report(7, 6440.into(), 5905.into());
+        report.part_two = None;
+        let reports = [(year, report)];
+
+        assert_eq!(reports[0].1.total(), Duration::from_micros(15));
+        assert!(summary(&reports).lines().nth(1).unwrap().contains(" - "));
+        assert!(to_csv(&reports).ends_with("2023,7,5000,6440,solved,10000,,,,15000\n"));
+        assert!(to_json(&reports).contains("\"part_two\": null"));
+    }
+
+    #[test]
+    fn test_failed_parts() {
+        let (year, mut report) = report(7, 6440.into(), 5905.into());
+        report.part_one = Some(Outcome::TimedOut(Duration::from_secs(1)));
+        report.part_two = Some(Outcome::Panicked(Panic {
+            message: "no start".to_string(),
+            location: Some("2023/src/days/day10.rs:12:5".to_string()),
+        }));
+        let reports = [(year, report)];
+
+        assert_eq!(reports[0].1.total(), Duration::from_micros(5));
+        let line = summary(&reports).lines().nth(1).unwrap().to_string();
+        assert!(line.contains("timed out") && line.contains("panicked"));
+        assert!(to_csv(&reports).ends_with("2023,7,5000,,timed out,,,panicked,,5000\n"));
+
+        let json: serde_json::Value = serde_json::from_str(&to_json(&reports)).unwrap();
+        assert_eq!(json[0]["part_one_error"], "timed out after 1.00s");
+        assert_eq!(
+            json[0]["part_two_error"],
+            "panicked at 2023/src/days/day10.rs:12:5: no start"
+        );
+
+        let report = Report::missing_input(3, Some(Part::Two));
+        assert!(report.part_one.is_none());
+        assert!(matches!(report.part_two, Some(Outcome::MissingInput)));
+
+        let err = AocError::MissingKey("03.txt.enc".into());
+        let report = Report::unreadable_input(3, None, &err);
+        assert_eq!(report.part_one.as_ref().unwrap().status(), "unreadable");
+        assert_eq!(report.part_two.unwrap().to_string(), err.to_string());
+    }
+
+    struct Count;
+
+    impl Solution for Count {
+        const DAY: u8 = 1;
+
+        type Input<'a> = usize;
+
+        fn parse(input: &str) -> Self::Input<'_> {
+            input.lines().count()
+        }
+
+        fn part_one(input: &Self::Input<'_>) -> Answer {
+            (*input).into()
+        }
+
+        fn part_two(input: &Self::Input<'_>) -> Answer {
+            (input * 2).into()
+        }
+    }
+
+    #[test]
+    fn test_bench() {
+        let day = Day::new::<Count>();
+        let mut runs = 0;
+
+        let report = bench(3, || {
+            runs += 1;
+            (day.solve)("a\nb\nc", None)
+        });
+        assert_eq!(runs, 3);
+        assert_eq!(report.part_one.unwrap().answer(), Some(&3.into()));
+        assert_eq!(report.part_two.unwrap().answer(), Some(&6.into()));
+
+        let report = bench(3, || (day.solve)("a\nb\nc", Some(Part::Two)));
+        assert!(report.part_one.is_none());
+        assert_eq!(report.part_two.unwrap().answer(), Some(&6.into()));
+
+        // A part that failed isn't solved again.
+        let mut runs = 0;
+        let report = bench(3, || {
+            runs += 1;
+            Report::missing_input(1, None)
+        });
+        assert_eq!(runs, 1);
+        assert!(matches!(report.part_one, Some(Outcome::MissingInput)));
+    }
+}
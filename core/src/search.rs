@@ -0,0 +1,249 @@
+//! Shortest paths over any state type, with Dijkstra's algorithm or A*.
+
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::{BinaryHeap, HashMap},
+    hash::Hash,
+};
+
+/// What a search found: the first goal it reached, and the optimal cost and
+/// every optimal predecessor of each state it settled on the way.
+#[derive(Debug, Clone)]
+pub struct Search<S> {
+    nodes: Vec<Node<S>>,
+    index: HashMap<S, usize>,
+    goal: Option<usize>,
+}
+
+#[derive(Debug, Clone)]
+struct Node<S> {
+    state: S,
+    cost: u64,
+    predecessors: Vec<usize>,
+    settled: bool,
+}
+
+/// Searches from every state in `starts` with Dijkstra's algorithm until it
+/// settles a state that `is_goal`. `neighbours` gives the states one step
+/// away from a state with the cost of the step.
+///
+/// Pass `|_| false` as `is_goal` to settle every reachable state.
+pub fn dijkstra<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    neighbours: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    astar(starts, neighbours, |_| 0, is_goal)
+}
+
+/// Like [`dijkstra`], but explores states in order of their cost plus the
+/// `heuristic` estimate of the cost left to a goal. The heuristic must never
+/// overestimate, and for the costs of states other than the goal to be
+/// optimal it must be consistent, like the Manhattan distance on a grid.
+pub fn astar<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> u64,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    let mut search = Search {
+        nodes: Vec::new(),
+        index: HashMap::new(),
+        goal: None,
+    };
+    // Ordered by the estimated total cost, then the cost so far.
+    let mut heap = BinaryHeap::new();
+
+    for start in starts {
+        let i = search.intern(start);
+        search.nodes[i].cost = 0;
+        heap.push(Reverse((heuristic(&search.nodes[i].state), 0, i)));
+    }
+
+    while let Some(Reverse((estimate, cost, i))) = heap.pop() {
+        // Keep going while other states could still be optimal predecessors
+        // of the goal.
+        if search.goal_cost().is_some_and(|goal| estimate > goal) {
+            break;
+        }
+        if search.nodes[i].settled || cost > search.nodes[i].cost {
+            continue;
+        }
+        search.nodes[i].settled = true;
+
+        let state = search.nodes[i].state.clone();
+        if search.goal.is_none() && is_goal(&state) {
+            search.goal = Some(i);
+            continue;
+        }
+
+        for (next, step) in neighbours(&state) {
+            let cost = cost + step;
+            let j = search.intern(next);
+            let node = &mut search.nodes[j];
+
+            match cost.cmp(&node.cost) {
+                Ordering::Less => {
+                    node.cost = cost;
+                    node.predecessors = vec![i];
+                    heap.push(Reverse((cost + heuristic(&node.state), cost, j)));
+                }
+                Ordering::Equal if !node.predecessors.contains(&i) => node.predecessors.push(i),
+                _ => {}
+            }
+        }
+    }
+
+    search
+}
+
+impl<S: Clone + Eq + Hash> Search<S> {
+    // The index of a state, adding it unreached if it is new.
+    fn intern(&mut self, state: S) -> usize {
+        if let Some(&i) = self.index.get(&state) {
+            return i;
+        }
+
+        let i = self.nodes.len();
+        self.index.insert(state.clone(), i);
+        self.nodes.push(Node {
+            state,
+            cost: u64::MAX,
+            predecessors: Vec::new(),
+            settled: false,
+        });
+        i
+    }
+
+    fn settled(&self, state: &S) -> Option<&Node<S>> {
+        let node = &self.nodes[*self.index.get(state)?];
+        node.settled.then_some(node)
+    }
+
+    /// The first goal the search reached.
+    pub fn goal(&self) -> Option<&S> {
+        Some(&self.nodes[self.goal?].state)
+    }
+
+    pub fn goal_cost(&self) -> Option<u64> {
+        Some(self.nodes[self.goal?].cost)
+    }
+
+    /// The optimal cost of reaching a state the search settled.
+    pub fn cost(&self, state: &S) -> Option<u64> {
+        self.settled(state).map(|node| node.cost)
+    }
+
+    /// Every state that an optimal path to `state` can come from, which is
+    /// none for a start state or a state the search didn't settle.
+    pub fn predecessors(&self, state: &S) -> impl Iterator<Item = &S> {
+        self.settled(state)
+            .into_iter()
+            .flat_map(|node| &node.predecessors)
+            .map(|&i| &self.nodes[i].state)
+    }
+
+    /// An optimal path from a start to `state`, both included.
+    pub fn path(&self, state: &S) -> Option<Vec<S>> {
+        let mut i = *self.index.get(state)?;
+        if !self.nodes[i].settled {
+            return None;
+        }
+        let mut path = vec![self.nodes[i].state.clone()];
+
+        while let Some(&previous) = self.nodes[i].predecessors.first() {
+            // Steps that cost nothing can make a predecessor loop back.
+            if path.len() > self.nodes.len() {
+                return None;
+            }
+            i = previous;
+            path.push(self.nodes[i].state.clone());
+        }
+
+        path.reverse();
+        Some(path)
+    }
+
+    /// An optimal path from a start to the goal, both included.
+    pub fn goal_path(&self) -> Option<Vec<S>> {
+        self.path(self.goal()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{grid::Pos, Grid};
+
+    // Moving onto a digit costs that digit, `#` is a wall.
+    const MAZE: &str = "\
+1163
+1#81
+1321
+";
+
+    fn neighbours(grid: &Grid<u8>) -> impl FnMut(&Pos) -> Vec<(Pos, u64)> + '_ {
+        |&pos| {
+            grid.neighbours4(pos)
+                .filter(|&next| grid[next] != b'#')
+                .map(|next| (next, (grid[next] - b'0') as u64))
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let grid = Grid::parse(MAZE, |b| b).unwrap();
+        let search = dijkstra([(0, 0)], neighbours(&grid), |&pos| pos == (3, 2));
+
+        assert_eq!(search.goal(), Some(&(3, 2)));
+        assert_eq!(search.goal_cost(), Some(8));
+        assert_eq!(
+            search.goal_path().unwrap(),
+            [(0, 0), (0, 1), (0, 2), (1, 2), (2, 2), (3, 2)]
+        );
+        assert_eq!(search.cost(&(2, 0)), Some(7));
+        assert_eq!(search.path(&(0, 0)).unwrap(), [(0, 0)]);
+    }
+
+    #[test]
+    fn test_predecessors() {
+        // Both ways around the wall to (2, 2) cost 4.
+        let grid = Grid::parse("111\n1#1\n111\n", |b| b).unwrap();
+        let search = dijkstra([(0, 0)], neighbours(&grid), |_| false);
+
+        assert_eq!(search.cost(&(2, 2)), Some(4));
+        let mut predecessors: Vec<_> = search.predecessors(&(2, 2)).collect();
+        predecessors.sort();
+        assert_eq!(predecessors, [&(1, 2), &(2, 1)]);
+        assert_eq!(search.predecessors(&(0, 0)).count(), 0);
+        assert!(search.goal().is_none());
+    }
+
+    #[test]
+    fn test_astar_and_starts() {
+        let grid = Grid::parse(MAZE, |b| b).unwrap();
+        let goal = (3, 0);
+        let manhattan = |&(x, y): &Pos| (x.abs_diff(goal.0) + y.abs_diff(goal.1)) as u64;
+
+        let search = astar([(0, 0)], neighbours(&grid), manhattan, |&pos| pos == goal);
+        assert_eq!(search.goal_cost(), Some(10));
+
+        let search = astar([(0, 0), (3, 2)], neighbours(&grid), manhattan, |&pos| {
+            pos == goal
+        });
+        assert_eq!(search.goal_cost(), Some(4));
+        assert_eq!(search.goal_path().unwrap(), [(3, 2), (3, 1), (3, 0)]);
+        assert!(dijkstra([(0, 0)], neighbours(&grid), |&pos| pos == (1, 1))
+            .goal()
+            .is_none());
+    }
+}
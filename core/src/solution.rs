@@ -0,0 +1,91 @@
+use std::{fmt, time::Instant};
+
+use crate::{
+    runner::{time, Outcome, Report},
+    Answer, Params,
+};
+
+/// A puzzle solution for a single day.
+///
+/// The input is parsed once and shared by both parts, so the runner can call
+/// every day in-process instead of spawning a binary per day.
+pub trait Solution {
+    /// The day of December the puzzle was released on.
+    const DAY: u8;
+
+    /// The parsed puzzle input, which may borrow from the raw input.
+    type Input<'a>;
+
+    fn parse(input: &str) -> Self::Input<'_>;
+    fn part_one(input: &Self::Input<'_>) -> Answer;
+    fn part_two(input: &Self::Input<'_>) -> Answer;
+
+    /// Solves part one with the numbers an example changes, such as taking
+    /// fewer steps than the puzzle. Only days whose examples set [`Params`]
+    /// override this.
+    fn part_one_with(input: &Self::Input<'_>, _params: &Params) -> Answer {
+        Self::part_one(input)
+    }
+
+    /// Solves part two with the numbers an example changes, see
+    /// [`Solution::part_one_with`].
+    fn part_two_with(input: &Self::Input<'_>, _params: &Params) -> Answer {
+        Self::part_two(input)
+    }
+}
+
+/// One of the two parts of a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "part one"),
+            Part::Two => write!(f, "part two"),
+        }
+    }
+}
+
+/// A type-erased [`Solution`], as stored in the registry of a [`Year`].
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub number: u8,
+    /// Parses the input and solves the given part, or both parts for `None`.
+    /// A panic in the solution unwinds out of it, see
+    /// [`isolation::solve_isolated`](crate::isolation::solve_isolated) to
+    /// catch it instead.
+    pub solve: fn(&str, Option<Part>) -> Report,
+}
+
+impl Day {
+    pub const fn new<S: Solution>() -> Self {
+        Day {
+            number: S::DAY,
+            solve: solve::<S>,
+        }
+    }
+}
+
+// Parsing is timed separately from the parts, so a day that does all of its
+// work in `parse` shows up as such in the summary.
+fn solve<S: Solution>(input: &str, part: Option<Part>) -> Report {
+    let start = Instant::now();
+    let input = S::parse(input);
+    let parse = start.elapsed();
+    let wanted = |p| part.is_none_or(|part| part == p);
+
+    Report {
+        day: S::DAY,
+        parse,
+        part_one: wanted(Part::One).then(|| Outcome::Solved(time(|| S::part_one(&input)))),
+        part_two: wanted(Part::Two).then(|| Outcome::Solved(time(|| S::part_two(&input)))),
+    }
+}
@@ -0,0 +1,282 @@
+//! Puzzle inputs encrypted at rest, so they can be committed without being
+//! published. An encrypted input sits next to where its plain text would be,
+//! as `NN.txt.enc`, and is read transparently when the plain text is missing.
+//!
+//! The files are ChaCha20-Poly1305 with a random nonce per file. The name of
+//! the input is authenticated with it, so one day's input can't be passed off
+//! as another's.
+
+use std::{
+    env, fmt,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use chacha20poly1305::{
+    aead::{Aead, AeadCore, KeyInit, OsRng, Payload},
+    ChaCha20Poly1305, Nonce,
+};
+
+use crate::AocError;
+
+/// Holds the key as 64 hex digits, and takes precedence over the key file.
+pub const KEY_VAR: &str = "AOC_INPUT_KEY";
+
+/// The extension added to the name of an encrypted input.
+pub const EXTENSION: &str = "enc";
+
+// Identifies the format, in case it ever has to change.
+const MAGIC: &[u8; 8] = b"aoc-enc1";
+const NONCE_LEN: usize = 12;
+
+/// A key for encrypting inputs, which stays on the machines that may read
+/// them.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Key([u8; 32]);
+
+impl Key {
+    pub fn generate() -> Self {
+        Key(ChaCha20Poly1305::generate_key(&mut OsRng).into())
+    }
+
+    pub fn from_hex(hex: &str) -> Result<Self, AocError> {
+        let hex = hex.trim();
+        let invalid = || AocError::InvalidKey(format!("expected 64 hex digits, got {:?}", hex));
+        if hex.len() != 64 || !hex.is_ascii() {
+            return Err(invalid());
+        }
+
+        let mut key = [0; 32];
+        for (byte, digits) in key.iter_mut().zip(hex.as_bytes().chunks(2)) {
+            let digits = std::str::from_utf8(digits).map_err(|_| invalid())?;
+            *byte = u8::from_str_radix(digits, 16).map_err(|_| invalid())?;
+        }
+        Ok(Key(key))
+    }
+
+    pub fn to_hex(&self) -> String {
+        self.0.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    /// Where the key is kept when `AOC_INPUT_KEY` isn't set:
+    /// `~/.config/aoc/inputs.key`.
+    pub fn default_path() -> Option<PathBuf> {
+        let home = env::var_os("HOME").filter(|home| !home.is_empty())?;
+        Some(Path::new(&home).join(".config/aoc/inputs.key"))
+    }
+
+    /// The key from `AOC_INPUT_KEY`, or from the key file. `None` if there
+    /// is neither.
+    pub fn load() -> Result<Option<Self>, AocError> {
+        if let Some(hex) = env::var_os(KEY_VAR).filter(|hex| !hex.is_empty()) {
+            return Key::from_hex(&hex.to_string_lossy()).map(Some);
+        }
+
+        match Key::default_path() {
+            Some(path) if path.exists() => {
+                let hex =
+                    fs::read_to_string(&path).map_err(|source| AocError::Io { path, source })?;
+                Key::from_hex(&hex).map(Some)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Writes the key to `path`, readable only by its owner, refusing to
+    /// replace a key that is already there.
+    pub fn save(&self, path: &Path) -> Result<(), AocError> {
+        let write_err = |source| AocError::Write {
+            path: path.to_path_buf(),
+            source,
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(write_err)?;
+        }
+
+        // Created with its permissions in one go, so the key is never
+        // readable by others and nothing can appear at `path` in between.
+        let mut options = OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        let mut file = options.open(path).map_err(|source| match source.kind() {
+            io::ErrorKind::AlreadyExists => AocError::AlreadyExists(path.to_path_buf()),
+            _ => write_err(source),
+        })?;
+
+        file.write_all((self.to_hex() + "\n").as_bytes())
+            .map_err(write_err)
+    }
+
+    fn cipher(&self) -> ChaCha20Poly1305 {
+        ChaCha20Poly1305::new(&self.0.into())
+    }
+}
+
+// Keeps the key out of panics and logs.
+impl fmt::Debug for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Key(..)")
+    }
+}
+
+/// The path the encrypted version of an input at `path` is stored at.
+pub fn encrypted_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".");
+    name.push(EXTENSION);
+    path.with_file_name(name)
+}
+
+/// Encrypts an input, where `name` is the file name of its plain text, such
+/// as `07.txt`.
+pub fn encrypt(key: &Key, name: &str, plaintext: &[u8]) -> Vec<u8> {
+    let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let payload = Payload {
+        msg: plaintext,
+        aad: name.as_bytes(),
+    };
+    let ciphertext = key
+        .cipher()
+        .encrypt(&nonce, payload)
+        .expect("inputs are far too small to exceed the ChaCha20 limit");
+
+    [MAGIC.as_slice(), nonce.as_slice(), &ciphertext].concat()
+}
+
+/// Decrypts an input stored at `path`, see [`encrypt`].
+pub fn decrypt(key: &Key, name: &str, data: &[u8], path: &Path) -> Result<Vec<u8>, AocError> {
+    let failed = |reason: &str| AocError::Decrypt {
+        path: path.to_path_buf(),
+        reason: reason.to_string(),
+    };
+
+    let data = data
+        .strip_prefix(MAGIC.as_slice())
+        .ok_or_else(|| failed("not an encrypted input"))?;
+    if data.len() < NONCE_LEN {
+        return Err(failed("the file is truncated"));
+    }
+    let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+    let payload = Payload {
+        msg: ciphertext,
+        aad: name.as_bytes(),
+    };
+
+    key.cipher()
+        .decrypt(Nonce::from_slice(nonce), payload)
+        .map_err(|_| failed("wrong key, or the file was changed or renamed"))
+}
+
+/// Reads the encrypted version of the input at `path`, if there is one.
+pub(crate) fn read_encrypted(path: &Path) -> Option<Result<String, AocError>> {
+    let encrypted = encrypted_path(path);
+    let data = match fs::read(&encrypted) {
+        Ok(data) => data,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return None,
+        Err(source) => {
+            return Some(Err(AocError::Io {
+                path: encrypted,
+                source,
+            }))
+        }
+    };
+
+    Some((|| {
+        let key = Key::load()?.ok_or_else(|| AocError::MissingKey(encrypted.clone()))?;
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        let plaintext = decrypt(&key, &name, &data, &encrypted)?;
+
+        String::from_utf8(plaintext).map_err(|_| AocError::Decrypt {
+            path: encrypted.clone(),
+            reason: "the input isn't UTF-8".to_string(),
+        })
+    })())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let key = Key::generate();
+        let path = Path::new("07.txt.enc");
+        let data = encrypt(&key, "07.txt", b"32T3K 765\n");
+
+        assert!(data.starts_with(MAGIC));
+        assert_eq!(
+            decrypt(&key, "07.txt", &data, path).unwrap(),
+            b"32T3K 765\n"
+        );
+        // A fresh nonce every time.
+        assert_ne!(data, encrypt(&key, "07.txt", b"32T3K 765\n"));
+
+        assert!(decrypt(&Key::generate(), "07.txt", &data, path).is_err());
+        assert!(decrypt(&key, "08.txt", &data, path).is_err());
+        assert!(decrypt(&key, "07.txt", &data[..15], path).is_err());
+        assert!(decrypt(&key, "07.txt", b"32T3K 765\n", path).is_err());
+    }
+
+    #[test]
+    fn test_key() {
+        let key = Key::generate();
+
+        assert_eq!(Key::from_hex(&key.to_hex()).unwrap(), key);
+        assert_eq!(
+            Key::from_hex(&format!("  {}\n", "0f".repeat(32))).unwrap(),
+            Key([15; 32])
+        );
+        assert!(Key::from_hex("0f").is_err());
+        assert!(Key::from_hex(&"zz".repeat(32)).is_err());
+        assert!(Key::from_hex(&"é".repeat(32)).is_err());
+    }
+
+    #[test]
+    fn test_save() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("aoc/inputs.key");
+        let key = Key::generate();
+
+        key.save(&path).unwrap();
+        assert_eq!(
+            Key::from_hex(&fs::read_to_string(&path).unwrap()).unwrap(),
+            key
+        );
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+        assert!(matches!(
+            Key::generate().save(&path),
+            Err(AocError::AlreadyExists(_))
+        ));
+    }
+
+    #[test]
+    fn test_read_encrypted() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("07.txt");
+
+        assert!(read_encrypted(&path).is_none());
+        fs::create_dir(encrypted_path(&path)).unwrap();
+        assert!(matches!(
+            read_encrypted(&path),
+            Some(Err(AocError::Io { .. }))
+        ));
+    }
+
+    #[test]
+    fn test_encrypted_path() {
+        assert_eq!(
+            encrypted_path(Path::new("2023/src/inputs/07.txt")),
+            Path::new("2023/src/inputs/07.txt.enc")
+        );
+    }
+}
@@ -0,0 +1,124 @@
+use std::{
+    env,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    answers::Answers,
+    examples::Examples,
+    input::{inputs_dir_from, read_input},
+    AocError, Day, INPUT_DIR_VAR,
+};
+
+/// The solved days of a year and the directory its inputs live in.
+///
+/// Each year crate defines one, with `dir` set to its own
+/// `CARGO_MANIFEST_DIR` so inputs resolve no matter where a binary is run from.
+#[derive(Clone, Copy)]
+pub struct Year {
+    pub number: u16,
+    pub dir: &'static str,
+    pub days: &'static [Day],
+}
+
+impl Year {
+    pub fn day(&self, number: u8) -> Option<&Day> {
+        self.days.iter().find(|day| day.number == number)
+    }
+
+    /// The directory puzzle inputs are read from: `$AOC_INPUT_DIR/<year>` if
+    /// set, `src/inputs` of the year otherwise.
+    pub fn inputs_dir(&self) -> PathBuf {
+        inputs_dir_from(Path::new(self.dir), self.number, env::var_os(INPUT_DIR_VAR))
+    }
+
+    /// The path puzzle inputs for a day are expected at.
+    pub fn input_path(&self, day: u8) -> PathBuf {
+        self.inputs_dir().join(format!("{:02}.txt", day))
+    }
+
+    pub fn read_input(&self, file: &str) -> Result<String, AocError> {
+        read_input(self.inputs_dir().join(file))
+    }
+
+    /// The directory example inputs and their manifest live in.
+    pub fn examples_dir(&self) -> PathBuf {
+        Path::new(self.dir).join("src/test_inputs")
+    }
+
+    /// Reads an example input from `src/test_inputs` of the year.
+    pub fn read_example(&self, file: &str) -> Result<String, AocError> {
+        read_input(self.examples_dir().join(file))
+    }
+
+    /// The examples in [`Year::examples_dir`] and the answers they give.
+    pub fn examples(&self) -> Result<Examples, AocError> {
+        Examples::load(&self.examples_dir())
+    }
+
+    /// The known answers to the inputs in [`Year::inputs_dir`].
+    pub fn answers(&self) -> Result<Answers, AocError> {
+        Answers::load(&self.inputs_dir())
+    }
+}
+
+/// Defines what every year crate has in common, given its number: the `YEAR`
+/// with the days of its `days` module, the input helpers, the re-exports the
+/// days use, and the tests of its answers and examples.
+#[macro_export]
+macro_rules! year {
+    ($number:literal) => {
+        pub use $crate::{
+            cycle, geometry, graph, interval, math, search, Answer, AocError, Grid, Params,
+            Solution,
+        };
+
+        #[doc = concat!("The solutions to Advent of Code ", stringify!($number), ".")]
+        pub const YEAR: $crate::Year = $crate::Year {
+            number: $number,
+            dir: env!("CARGO_MANIFEST_DIR"),
+            days: days::DAYS,
+        };
+
+        pub fn read_file_input(file: String) -> Result<String, AocError> {
+            YEAR.read_input(&file)
+        }
+
+        pub fn read_test_file_input(file: String) -> Result<String, AocError> {
+            YEAR.read_example(&file)
+        }
+
+        #[cfg(test)]
+        fn assert_known_answers<S: Solution>() {
+            $crate::assert_known_answers::<S>(&YEAR);
+        }
+
+        #[cfg(test)]
+        fn assert_example<S: Solution>(index: usize) {
+            $crate::assert_example::<S>(&YEAR, index);
+        }
+
+        // A test per example in `src/test_inputs/examples.toml`, generated by
+        // the build script.
+        #[cfg(test)]
+        mod examples {
+            include!(concat!(env!("OUT_DIR"), "/examples.rs"));
+        }
+
+        #[cfg(test)]
+        mod tests {
+            use super::*;
+
+            #[test]
+            fn test_answered_days_are_registered() {
+                for day in YEAR.answers().unwrap().days() {
+                    assert!(
+                        YEAR.day(day).is_some(),
+                        "day {:02} has answers but is not registered",
+                        day
+                    );
+                }
+            }
+        }
+    };
+}